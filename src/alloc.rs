@@ -0,0 +1,142 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+// Counting every allocation costs a little time, so it's opt-in
+pub const ENABLED: bool = cfg!(feature = "alloc-stats");
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+// What one phase allocated on the thread that ran it. Peak is the most bytes
+// that were live at once, on top of what was live when the phase started.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AllocStats {
+    pub bytes: u64,
+    pub count: u64,
+    pub peak: u64,
+}
+
+#[derive(Clone, Copy)]
+struct Counters {
+    bytes: u64,
+    count: u64,
+    // Signed, memory freed on another thread than it was allocated on
+    // makes it go down further than it went up here
+    live: i64,
+    peak: i64,
+}
+
+// Kept per thread, so days solved in parallel don't count each other's memory
+thread_local! {
+    static COUNTERS: Cell<Counters> = const {
+        Cell::new(Counters {
+            bytes: 0,
+            count: 0,
+            live: 0,
+            peak: 0,
+        })
+    };
+}
+
+// Thread locals are gone while a thread shuts down, whatever it still
+// allocates then isn't counted
+fn update<F: FnOnce(&mut Counters)>(func: F) {
+    let _ = COUNTERS.try_with(|cell| {
+        let mut counters = cell.get();
+        func(&mut counters);
+        cell.set(counters);
+    });
+}
+
+fn allocated(size: usize) {
+    update(|x| {
+        x.bytes += size as u64;
+        x.count += 1;
+        x.live += size as i64;
+        x.peak = x.peak.max(x.live);
+    });
+}
+
+fn freed(size: usize) {
+    update(|x| x.live -= size as i64);
+}
+
+pub struct CountingAlloc;
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        freed(layout.size());
+    }
+
+    // A growing Vec counts as a new allocation every time it moves
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            freed(layout.size());
+            allocated(new_size);
+        }
+        new_ptr
+    }
+}
+
+// Runs `func` and reports what it allocated, None without the counting allocator
+pub fn measure<T, F: FnOnce() -> T>(func: F) -> (T, Option<AllocStats>) {
+    if !ENABLED {
+        return (func(), None);
+    }
+
+    let start = COUNTERS.with(|cell| {
+        let mut counters = cell.get();
+        counters.peak = counters.live;
+        cell.set(counters);
+        counters
+    });
+    let res = func();
+    let end = COUNTERS.with(Cell::get);
+
+    let stats = AllocStats {
+        bytes: end.bytes - start.bytes,
+        count: end.count - start.count,
+        peak: (end.peak - start.live).max(0) as u64,
+    };
+    (res, Some(stats))
+}
+
+#[cfg(all(test, feature = "alloc-stats"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_allocations() {
+        let (len, stats) = measure(|| {
+            let first = vec![0u8; 1000];
+            drop(first);
+            let second = vec![0u64; 100];
+            second.len()
+        });
+        assert_eq!(len, 100);
+        let stats = stats.unwrap();
+        assert_eq!(stats.count, 2);
+        assert_eq!(stats.bytes, 1800);
+        // Only one of the two was alive at a time
+        assert_eq!(stats.peak, 1000);
+    }
+}
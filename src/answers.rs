@@ -0,0 +1,400 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+use rustaocean2020::common::{self, Part};
+use rustaocean2020::error::{Error, Result};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+lazy_static! {
+    static ref DAY_HEADER: Regex = Regex::new(r"^\[day(\d+)\]\s*(?:#.*)?$").unwrap();
+    static ref PART_ANSWER: Regex =
+        Regex::new(r#"^part([12]) = "((?:[^"\\]|\\.)*)"\s*(?:#.*)?$"#).unwrap();
+}
+
+pub fn default_path(year: u16) -> PathBuf {
+    common::year_input_dir(year).join("answers.toml")
+}
+
+// Answers of a named input live next to it, `alice.in` has `alice.toml`
+pub fn input_path(input: &Path) -> PathBuf {
+    input.with_extension("toml")
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail(String),
+    New,
+}
+
+impl Verdict {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Verdict::Pass => "pass",
+            Verdict::Fail(_) => "fail",
+            Verdict::New => "new",
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail(expected) => write!(f, "FAIL (expected {})", expected),
+            Verdict::New => write!(f, "NEW"),
+        }
+    }
+}
+
+fn part_idx(part: Part) -> usize {
+    match part {
+        Part::One => 0,
+        Part::Two => 1,
+    }
+}
+
+// Escapes of TOML basic strings, an answer has to fit on its line
+fn escape(txt: &str) -> String {
+    let mut res = String::new();
+    for c in txt.chars() {
+        match c {
+            '"' | '\\' => {
+                res.push('\\');
+                res.push(c);
+            }
+            '\n' => res.push_str("\\n"),
+            '\r' => res.push_str("\\r"),
+            '\t' => res.push_str("\\t"),
+            c if c.is_control() => res.push_str(&format!("\\u{:04X}", c as u32)),
+            c => res.push(c),
+        }
+    }
+    res
+}
+
+fn unescape(txt: &str) -> String {
+    let mut res = String::new();
+    let mut chars = txt.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            res.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => res.push('\n'),
+            Some('r') => res.push('\r'),
+            Some('t') => res.push('\t'),
+            Some('u') => {
+                let hex: String = chars.by_ref().take(4).collect();
+                let code = match hex.len() == 4 && hex.chars().all(|x| x.is_ascii_hexdigit()) {
+                    true => u32::from_str_radix(&hex, 16).ok(),
+                    false => None,
+                };
+                match code.and_then(char::from_u32) {
+                    Some(c) => res.push(c),
+                    None => {
+                        res.push('u');
+                        res.push_str(&hex);
+                    }
+                }
+            }
+            next => res.extend(next),
+        }
+    }
+    res
+}
+
+enum Entry {
+    Table(u8),
+    Answer(Part, String),
+}
+
+// None for blank lines and comments
+fn parse_entry(line: &str) -> std::result::Result<Option<Entry>, String> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        Ok(None)
+    } else if let Some(caps) = DAY_HEADER.captures(line) {
+        let num = caps[1].parse().map_err(|_| "invalid day".to_string())?;
+        Ok(Some(Entry::Table(num)))
+    } else if let Some(caps) = PART_ANSWER.captures(line) {
+        Ok(Some(Entry::Answer(caps[1].parse()?, unescape(&caps[2]))))
+    } else {
+        Err("expected `[dayNN]` or `partN = \"answer\"`".to_string())
+    }
+}
+
+// Known answers per day, kept in a small subset of TOML:
+//   [day05]
+//   part1 = "928"
+//   part2 = "610"
+pub struct Answers {
+    path: PathBuf,
+    days: BTreeMap<u8, [Option<String>; 2]>,
+    // The file as loaded, saving keeps its comments and layout
+    lines: Vec<String>,
+}
+
+impl Answers {
+    // A missing file is fine, every answer is just new then
+    pub fn load(path: &Path) -> Result<Answers> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(Error::io(path, e)),
+        };
+        Answers::parse(path, &text)
+    }
+
+    fn parse(path: &Path, text: &str) -> Result<Answers> {
+        let mut answers = Answers {
+            path: path.to_path_buf(),
+            days: BTreeMap::new(),
+            lines: text.lines().map(str::to_string).collect(),
+        };
+
+        let mut day = None;
+        for (idx, line) in text.lines().enumerate() {
+            let line_err = |msg: &str| {
+                Error::parse(format!("{}: {}", path.display(), msg)).at_line(idx + 1, line)
+            };
+            match parse_entry(line).map_err(|e| line_err(&e))? {
+                Some(Entry::Table(num)) => {
+                    answers.days.entry(num).or_default();
+                    day = Some(num);
+                }
+                Some(Entry::Answer(part, answer)) => {
+                    let num = day.ok_or_else(|| line_err("answer outside of a `[dayNN]` table"))?;
+                    answers.days.entry(num).or_default()[part_idx(part)] = Some(answer);
+                }
+                None => {}
+            }
+        }
+        Ok(answers)
+    }
+
+    pub fn check(&self, day: u8, part: Part, answer: &str) -> Verdict {
+        match self.days.get(&day).and_then(|x| x[part_idx(part)].as_ref()) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail(expected.to_string()),
+            None => Verdict::New,
+        }
+    }
+
+    // Never overwrites a known answer, those only change by editing the file
+    pub fn record(&mut self, day: u8, part: Part, answer: &str) -> bool {
+        let known = &mut self.days.entry(day).or_default()[part_idx(part)];
+        if known.is_some() {
+            return false;
+        }
+        *known = Some(answer.to_string());
+        true
+    }
+
+    // The loaded file with the recorded answers added after the last entry
+    // of their table, or in new tables at the end
+    fn render(&self) -> String {
+        let entries: Vec<Option<Entry>> = self
+            .lines
+            .iter()
+            .map(|x| parse_entry(x).ok().flatten())
+            .collect();
+
+        let mut in_file = BTreeSet::new();
+        let mut day = None;
+        for entry in &entries {
+            match entry {
+                Some(Entry::Table(num)) => day = Some(*num),
+                Some(Entry::Answer(part, _)) => in_file.extend(day.map(|x| (x, part_idx(*part)))),
+                None => {}
+            }
+        }
+        let mut added: BTreeMap<u8, Vec<String>> = BTreeMap::new();
+        for (&day, parts) in &self.days {
+            for (idx, answer) in parts.iter().enumerate() {
+                if let Some(answer) = answer.as_ref().filter(|_| !in_file.contains(&(day, idx))) {
+                    let line = format!("part{} = \"{}\"", idx + 1, escape(answer));
+                    added.entry(day).or_default().push(line);
+                }
+            }
+        }
+
+        let mut out: Vec<String> = Vec::new();
+        // Table being copied and where its last entry ended up in `out`
+        let mut table: Option<(u8, usize)> = None;
+        let mut flush = |out: &mut Vec<String>, table: Option<(u8, usize)>| {
+            if let Some((day, last)) = table {
+                let lines = added.remove(&day).unwrap_or_default();
+                out.splice(last + 1..last + 1, lines);
+            }
+        };
+        for (line, entry) in self.lines.iter().zip(&entries) {
+            match entry {
+                Some(Entry::Table(num)) => {
+                    flush(&mut out, table);
+                    table = Some((*num, out.len()));
+                }
+                Some(Entry::Answer(..)) => {
+                    if let Some((_, last)) = table.as_mut() {
+                        *last = out.len();
+                    }
+                }
+                None => {}
+            }
+            out.push(line.clone());
+        }
+        flush(&mut out, table);
+
+        for (day, lines) in added {
+            if out.last().is_some_and(|x| !x.trim().is_empty()) {
+                out.push(String::new());
+            }
+            out.push(format!("[day{:02}]", day));
+            out.extend(lines);
+        }
+        out.iter().map(|x| format!("{}\n", x)).collect()
+    }
+
+    pub fn save(&self) -> Result<()> {
+        fs::write(&self.path, self.render()).map_err(|e| Error::io(&self.path, e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static FILE: &str = "\
+# Answers of my inputs
+[day01]
+part1 = \"1\" # checked by hand
+part2 = \"2\"
+
+# Part 2 is still missing
+[day03]
+part1 = \"a \\\"b\\\" \\\\ c\"
+
+[day07] # not solved yet
+";
+
+    fn answers(text: &str) -> Answers {
+        Answers::parse(Path::new("answers.toml"), text).unwrap()
+    }
+
+    #[test]
+    fn escaping() {
+        for txt in [
+            "",
+            "plain",
+            "a\"b",
+            "back\\slash",
+            "line\nbreak\r\n",
+            "\ttab",
+            "\u{1}\u{7f}",
+            "zażółć",
+        ] {
+            let escaped = escape(txt);
+            assert!(!escaped.contains('\n'), "{:?}", escaped);
+            assert_eq!(unescape(&escaped), txt);
+            let line = format!("part1 = \"{}\"", escaped);
+            assert!(PART_ANSWER.is_match(&line), "{}", line);
+        }
+        assert_eq!(escape("a\"\\\n"), "a\\\"\\\\\\n");
+        assert_eq!(unescape("\\u00e9\\uZZ"), "éuZZ");
+    }
+
+    #[test]
+    fn loads() {
+        let answers = answers(FILE);
+        assert_eq!(answers.check(1, Part::One, "1"), Verdict::Pass);
+        assert_eq!(
+            answers.check(1, Part::Two, "3"),
+            Verdict::Fail("2".to_string())
+        );
+        assert_eq!(answers.check(3, Part::One, "a \"b\" \\ c"), Verdict::Pass);
+        assert_eq!(answers.check(3, Part::Two, "x"), Verdict::New);
+        assert_eq!(answers.check(9, Part::One, "x"), Verdict::New);
+
+        let err = Answers::parse(Path::new("a.toml"), "part1 = \"1\"")
+            .err()
+            .unwrap();
+        assert_eq!(err.line(), Some(1));
+        let err = Answers::parse(Path::new("a.toml"), "[day01]\npart3 = \"1\"")
+            .err()
+            .unwrap();
+        assert_eq!(err.line(), Some(2));
+    }
+
+    #[test]
+    fn records_without_overwriting() {
+        let mut answers = answers(FILE);
+        assert!(!answers.record(1, Part::One, "5"));
+        assert!(answers.record(3, Part::Two, "x"));
+        assert!(!answers.record(3, Part::Two, "y"));
+        assert_eq!(answers.check(1, Part::One, "1"), Verdict::Pass);
+        assert_eq!(answers.check(3, Part::Two, "x"), Verdict::Pass);
+    }
+
+    #[test]
+    fn saves_recorded_answers_in_place() {
+        let mut answers = answers(FILE);
+        answers.record(3, Part::Two, "two\nlines");
+        answers.record(7, Part::Two, "7");
+        answers.record(7, Part::One, "\"7\"");
+        answers.record(12, Part::One, "12");
+        let text = answers.render();
+        assert_eq!(
+            text,
+            "\
+# Answers of my inputs
+[day01]
+part1 = \"1\" # checked by hand
+part2 = \"2\"
+
+# Part 2 is still missing
+[day03]
+part1 = \"a \\\"b\\\" \\\\ c\"
+part2 = \"two\\nlines\"
+
+[day07] # not solved yet
+part1 = \"\\\"7\\\"\"
+part2 = \"7\"
+
+[day12]
+part1 = \"12\"
+"
+        );
+
+        // Nothing new, nothing changes
+        let reloaded = Answers::parse(Path::new("answers.toml"), &text).unwrap();
+        assert_eq!(reloaded.render(), text);
+        assert_eq!(reloaded.check(3, Part::Two, "two\nlines"), Verdict::Pass);
+        assert_eq!(reloaded.check(7, Part::One, "\"7\""), Verdict::Pass);
+    }
+
+    #[test]
+    fn round_trips_through_the_file() {
+        let dir = std::env::temp_dir().join(format!("answers_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("answers.toml");
+
+        let mut answers = Answers::load(&path).unwrap();
+        assert_eq!(answers.check(5, Part::One, "928"), Verdict::New);
+        answers.record(5, Part::One, "928");
+        answers.record(5, Part::Two, "6\\10");
+        answers.save().unwrap();
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "[day05]\npart1 = \"928\"\npart2 = \"6\\\\10\"\n"
+        );
+
+        let answers = Answers::load(&path).unwrap();
+        assert_eq!(answers.check(5, Part::One, "928"), Verdict::Pass);
+        assert_eq!(answers.check(5, Part::Two, "6\\10"), Verdict::Pass);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,394 @@
+use crate::error::{Error, Result};
+use crate::grid::{Grid, ADJACENT};
+use crate::hex::Hex;
+use crate::points::PointN;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::hash::Hash;
+use std::str::FromStr;
+
+// Which neighbour counts make a dead cell come alive and keep a live one
+// alive, written like Life's `B3/S23`. Counts go up to 9, higher ones never
+// match.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Rule {
+    birth: u16,
+    survive: u16,
+}
+
+impl Rule {
+    pub fn new(birth: &[u32], survive: &[u32]) -> Result<Self> {
+        let mask = |counts: &[u32]| {
+            counts.iter().try_fold(0, |mask, &x| match x {
+                0..=9 => Ok(mask | 1 << x),
+                _ => Err(Error::parse(format!("neighbour count {} is above 9", x))),
+            })
+        };
+        Ok(Rule {
+            birth: mask(birth)?,
+            survive: mask(survive)?,
+        })
+    }
+
+    pub fn next(&self, alive: bool, neighbours: u32) -> bool {
+        let mask = if alive { self.survive } else { self.birth };
+        neighbours <= 9 && mask & 1 << neighbours != 0
+    }
+}
+
+impl FromStr for Rule {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let err = || Error::parse(format!("rule `{}` isn't like `B3/S23`", s));
+        let counts = |text: Option<&str>, prefix: char| -> Result<Vec<u32>> {
+            let text = text.and_then(|x| x.strip_prefix(prefix)).ok_or_else(err)?;
+            text.chars()
+                .map(|c| c.to_digit(10).ok_or_else(err))
+                .collect()
+        };
+        let mut parts = s.split('/');
+        let birth = counts(parts.next(), 'B')?;
+        let survive = counts(parts.next(), 'S')?;
+        if parts.next().is_some() {
+            return Err(err());
+        }
+        Rule::new(&birth, &survive)
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let digits = |mask: u16| -> String {
+            (0..=9)
+                .filter(|x| mask & 1 << x != 0)
+                .map(|x| x.to_string())
+                .collect()
+        };
+        write!(f, "B{}/S{}", digits(self.birth), digits(self.survive))
+    }
+}
+
+// How cells are connected
+pub trait Topology {
+    type Cell: Copy + Eq + Hash;
+
+    fn for_each_neighbour<F: FnMut(Self::Cell)>(&self, cell: Self::Cell, func: F);
+}
+
+// A topology with a fixed number of cells, numbered from 0, which the dense
+// backend can keep in a Vec
+pub trait Bounded: Topology<Cell = usize> {
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+// The cells of a 2D map that are part of the automaton, e.g. the seats of a
+// waiting area, with neighbour lists worked out once up front
+pub struct GridTopology {
+    positions: Vec<(usize, usize)>,
+    neighbours: Vec<Vec<usize>>,
+}
+
+impl GridTopology {
+    fn build<F>(cells: &Grid<bool>, mut neighbours_of: F) -> Self
+    where
+        F: FnMut((usize, usize)) -> Vec<(usize, usize)>,
+    {
+        let positions: Vec<(usize, usize)> = cells.positions().filter(|&x| cells[x]).collect();
+        let mut index = Grid::new(cells.width(), cells.height(), usize::MAX);
+        positions
+            .iter()
+            .enumerate()
+            .for_each(|(idx, &pos)| index[pos] = idx);
+        let neighbours = positions
+            .iter()
+            .map(|&pos| neighbours_of(pos).iter().map(|&x| index[x]).collect())
+            .collect();
+        GridTopology {
+            positions,
+            neighbours,
+        }
+    }
+
+    // Up to 8 neighbours, the surrounding cells that are part of it
+    pub fn square(cells: &Grid<bool>) -> Self {
+        GridTopology::build(cells, |pos| {
+            cells.neighbours8(pos).filter(|&x| cells[x]).collect()
+        })
+    }
+
+    // Up to 8 neighbours, the first cell that's part of it in every direction
+    pub fn visibility(cells: &Grid<bool>) -> Self {
+        GridTopology::build(cells, |pos| {
+            ADJACENT
+                .iter()
+                .filter_map(|&dir| cells.ray(pos, dir).find(|&x| cells[x]))
+                .collect()
+        })
+    }
+
+    pub fn position(&self, cell: usize) -> (usize, usize) {
+        self.positions[cell]
+    }
+}
+
+impl Topology for GridTopology {
+    type Cell = usize;
+
+    fn for_each_neighbour<F: FnMut(usize)>(&self, cell: usize, func: F) {
+        self.neighbours[cell].iter().copied().for_each(func);
+    }
+}
+
+impl Bounded for GridTopology {
+    fn len(&self) -> usize {
+        self.positions.len()
+    }
+}
+
+// Infinite N dimensional lattice, every point has 3^N - 1 neighbours
+pub struct Lattice<const N: usize>;
+
+impl<const N: usize> Topology for Lattice<N> {
+    type Cell = PointN<N>;
+
+    fn for_each_neighbour<F: FnMut(PointN<N>)>(&self, cell: PointN<N>, func: F) {
+        cell.neighbours().for_each(func);
+    }
+}
+
+// Infinite hex grid, every hex has 6 neighbours
+pub struct HexGrid;
+
+impl Topology for HexGrid {
+    type Cell = Hex;
+
+    fn for_each_neighbour<F: FnMut(Hex)>(&self, cell: Hex, func: F) {
+        cell.neighbours().for_each(func);
+    }
+}
+
+pub trait Automaton {
+    // Advances one generation, false when nothing changed
+    fn step(&mut self) -> bool;
+
+    fn population(&self) -> usize;
+
+    fn run(&mut self, generations: usize) {
+        for _ in 0..generations {
+            self.step();
+        }
+    }
+
+    // Steps until a generation changes nothing and returns how many did.
+    // None when it still changes after `max_generations`.
+    fn run_to_fixpoint(&mut self, max_generations: usize) -> Option<usize> {
+        (1..=max_generations).find(|_| !self.step()).map(|x| x - 1)
+    }
+}
+
+// Every cell of a bounded topology, alive or not
+pub struct Dense<T: Bounded> {
+    topology: T,
+    rule: Rule,
+    alive: Vec<bool>,
+    counts: Vec<u32>,
+}
+
+impl<T: Bounded> Dense<T> {
+    // Starts with every cell dead
+    pub fn new(topology: T, rule: Rule) -> Self {
+        let len = topology.len();
+        Dense {
+            topology,
+            rule,
+            alive: vec![false; len],
+            counts: vec![0; len],
+        }
+    }
+
+    pub fn set(&mut self, cell: usize, alive: bool) {
+        self.alive[cell] = alive;
+    }
+
+    pub fn is_alive(&self, cell: usize) -> bool {
+        self.alive[cell]
+    }
+
+    pub fn topology(&self) -> &T {
+        &self.topology
+    }
+}
+
+impl<T: Bounded> Automaton for Dense<T> {
+    fn step(&mut self) -> bool {
+        let counts = &mut self.counts;
+        counts.iter_mut().for_each(|x| *x = 0);
+        for (cell, _) in self.alive.iter().enumerate().filter(|(_, &x)| x) {
+            self.topology.for_each_neighbour(cell, |x| counts[x] += 1);
+        }
+
+        let mut changed = false;
+        for (alive, &count) in self.alive.iter_mut().zip(counts.iter()) {
+            let next = self.rule.next(*alive, count);
+            changed |= next != *alive;
+            *alive = next;
+        }
+        changed
+    }
+
+    fn population(&self) -> usize {
+        self.alive.iter().filter(|&&x| x).count()
+    }
+}
+
+// Only the live cells, for topologies without an edge. Cells far from any
+// live one can't be born, so rules with B0 don't work here.
+pub struct Sparse<T: Topology> {
+    topology: T,
+    rule: Rule,
+    alive: HashSet<T::Cell>,
+}
+
+impl<T: Topology> Sparse<T> {
+    pub fn new<I: IntoIterator<Item = T::Cell>>(topology: T, rule: Rule, alive: I) -> Result<Self> {
+        if rule.next(false, 0) {
+            return Err(Error::solve(format!("{} needs a dense automaton", rule)));
+        }
+        Ok(Sparse {
+            topology,
+            rule,
+            alive: alive.into_iter().collect(),
+        })
+    }
+
+    pub fn is_alive(&self, cell: &T::Cell) -> bool {
+        self.alive.contains(cell)
+    }
+
+    pub fn alive(&self) -> impl Iterator<Item = &T::Cell> {
+        self.alive.iter()
+    }
+}
+
+impl<T: Topology> Automaton for Sparse<T> {
+    fn step(&mut self) -> bool {
+        let mut counts: HashMap<T::Cell, u32> = HashMap::new();
+        for &cell in &self.alive {
+            self.topology
+                .for_each_neighbour(cell, |x| *counts.entry(x).or_default() += 1);
+        }
+
+        // Live cells without live neighbours aren't counted at all
+        let lonely = self
+            .alive
+            .iter()
+            .filter(|&x| !counts.contains_key(x) && self.rule.next(true, 0))
+            .copied();
+        let next: HashSet<T::Cell> = counts
+            .iter()
+            .filter(|(cell, &count)| self.rule.next(self.alive.contains(cell), count))
+            .map(|(&cell, _)| cell)
+            .chain(lonely)
+            .collect();
+
+        let changed = next != self.alive;
+        self.alive = next;
+        changed
+    }
+
+    fn population(&self) -> usize {
+        self.alive.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::example_lines;
+
+    fn life() -> Rule {
+        "B3/S23".parse().unwrap()
+    }
+
+    #[test]
+    fn rules() {
+        let rule = life();
+        assert!(rule.next(false, 3));
+        assert!(!rule.next(false, 2));
+        assert!(rule.next(true, 2));
+        assert!(!rule.next(true, 4));
+        assert!(!rule.next(true, 26));
+        assert_eq!(rule, Rule::new(&[3], &[3, 2]).unwrap());
+        assert_eq!(rule.to_string(), "B3/S23");
+        assert_eq!(Rule::new(&[0], &[]).unwrap().to_string(), "B0/S");
+        assert!(Rule::new(&[3], &[2, 10]).is_err());
+
+        for bad in &["", "B3", "S23/B3", "B3/S2x", "B3/S23/", "b3/s23"] {
+            assert!(bad.parse::<Rule>().is_err(), "{}", bad);
+        }
+    }
+
+    #[test]
+    fn sparse_blinker() {
+        let cells = (-1..=1).map(|x| PointN::new([x, 0]));
+        let mut blinker = Sparse::new(Lattice::<2>, life(), cells).unwrap();
+        assert!(blinker.step());
+        assert!(blinker.is_alive(&PointN::new([0, -1])));
+        assert!(!blinker.is_alive(&PointN::new([-1, 0])));
+        blinker.run(9);
+        assert_eq!(blinker.population(), 3);
+        assert!(blinker.is_alive(&PointN::new([-1, 0])));
+        assert_eq!(blinker.run_to_fixpoint(10), None);
+
+        // Survival without neighbours keeps a lone cell
+        let rule = Rule::new(&[2], &[0]).unwrap();
+        let mut lone = Sparse::new(HexGrid, rule, vec![Hex::origin()]).unwrap();
+        assert_eq!(lone.run_to_fixpoint(10), Some(0));
+        assert_eq!(lone.population(), 1);
+
+        // Births without neighbours would fill the whole plane
+        let rule = Rule::new(&[0], &[]).unwrap();
+        assert!(Sparse::new(HexGrid, rule, vec![Hex::origin()]).is_err());
+    }
+
+    #[test]
+    fn dense_grid() {
+        let lines = example_lines(".##\n.##\n...");
+        let cells = Grid::parse(&lines, |c| Some(c == '#')).unwrap();
+        let all = Grid::new(3, 3, true);
+
+        // A block is still life
+        let mut block = Dense::new(GridTopology::square(&all), life());
+        all.positions()
+            .enumerate()
+            .for_each(|(idx, pos)| block.set(idx, cells[pos]));
+        assert_eq!(block.run_to_fixpoint(5), Some(0));
+        assert_eq!(block.population(), 4);
+        assert_eq!(block.topology().position(4), (1, 1));
+
+        // Only the cells that are part of it can come alive
+        let rule = Rule::new(&[0], &[]).unwrap();
+        let mut filled = Dense::new(GridTopology::square(&cells), rule);
+        assert!(filled.step());
+        assert_eq!(filled.population(), 4);
+        assert_eq!(filled.topology().len(), 4);
+    }
+
+    #[test]
+    fn visibility() {
+        let lines = example_lines("#.#\n...\n#.#");
+        let cells = Grid::parse(&lines, |c| Some(c == '#')).unwrap();
+        let count = |topology: &GridTopology| {
+            let mut res = 0;
+            topology.for_each_neighbour(0, |_| res += 1);
+            res
+        };
+        assert_eq!(count(&GridTopology::square(&cells)), 0);
+        assert_eq!(count(&GridTopology::visibility(&cells)), 3);
+    }
+}
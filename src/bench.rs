@@ -0,0 +1,190 @@
+use rustaocean2020::common::Part;
+use rustaocean2020::error::Result;
+use rustaocean2020::solver::{DaySolver, Timings};
+use std::fmt;
+use std::time::Duration;
+
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    // None when there's nothing to summarize, e.g. for a part that wasn't run
+    pub fn from_samples(samples: &[Duration]) -> Option<Stats> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+        let len = sorted.len();
+
+        let median = if len.is_multiple_of(2) {
+            (sorted[len / 2 - 1] + sorted[len / 2]) / 2
+        } else {
+            sorted[len / 2]
+        };
+        // Nearest-rank percentile, so it's always one of the measured values
+        let p95 = sorted[(len * 95).div_ceil(100) - 1];
+
+        let secs: Vec<f64> = sorted.iter().map(Duration::as_secs_f64).collect();
+        let mean = secs.iter().sum::<f64>() / len as f64;
+        let variance = secs.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / len as f64;
+
+        Some(Stats {
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            p95,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+pub struct BenchReport {
+    pub day: u8,
+    pub input: Option<String>,
+    pub warmup: usize,
+    pub iterations: usize,
+    pub parse: Stats,
+    pub part1: Option<Stats>,
+    pub part2: Option<Stats>,
+}
+
+// Warmup runs are thrown away, they're only there to fill caches and let the CPU clock up
+pub fn bench(
+    day: u8,
+    input_name: Option<&str>,
+    solver: &dyn DaySolver,
+    input: &[String],
+    part: Option<Part>,
+    warmup: usize,
+    iterations: usize,
+) -> Result<BenchReport> {
+    for _ in 0..warmup {
+        solver.run(input, part)?;
+    }
+
+    let timings = (0..iterations)
+        .map(|_| solver.run(input, part).map(|x| x.timings))
+        .collect::<Result<Vec<Timings>>>()?;
+
+    let parse: Vec<Duration> = timings.iter().map(|x| x.parse).collect();
+    let part1: Vec<Duration> = timings.iter().filter_map(|x| x.part1).collect();
+    let part2: Vec<Duration> = timings.iter().filter_map(|x| x.part2).collect();
+
+    Ok(BenchReport {
+        day,
+        input: input_name.map(str::to_string),
+        warmup,
+        iterations,
+        parse: Stats::from_samples(&parse).expect("bench needs at least one iteration"),
+        part1: Stats::from_samples(&part1),
+        part2: Stats::from_samples(&part2),
+    })
+}
+
+fn write_row(f: &mut fmt::Formatter, name: &str, stats: &Stats) -> fmt::Result {
+    writeln!(
+        f,
+        "  {:<6}{:>12.2?}{:>12.2?}{:>12.2?}{:>12.2?}{:>12.2?}",
+        name, stats.min, stats.median, stats.mean, stats.p95, stats.stddev
+    )
+}
+
+impl fmt::Display for BenchReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Bench for day{:02}", self.day)?;
+        if let Some(name) = &self.input {
+            write!(f, " [{}]", name)?;
+        }
+        writeln!(
+            f,
+            ": {} iterations after {} warmup",
+            self.iterations, self.warmup
+        )?;
+        writeln!(
+            f,
+            "  {:<6}{:>12}{:>12}{:>12}{:>12}{:>12}",
+            "", "min", "median", "mean", "p95", "stddev"
+        )?;
+        write_row(f, "parse", &self.parse)?;
+        if let Some(stats) = &self.part1 {
+            write_row(f, "part1", stats)?;
+        }
+        if let Some(stats) = &self.part2 {
+            write_row(f, "part2", stats)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&x| Duration::from_millis(x)).collect()
+    }
+
+    // Mean and stddev go through f64 seconds, so they may be off by a nanosecond
+    fn assert_close(actual: Duration, expected: Duration) {
+        let diff = actual.as_nanos().abs_diff(expected.as_nanos());
+        assert!(diff <= 1, "{:?} != {:?}", actual, expected);
+    }
+
+    #[test]
+    fn no_samples() {
+        assert!(Stats::from_samples(&[]).is_none());
+    }
+
+    #[test]
+    fn single_sample() {
+        let stats = Stats::from_samples(&millis(&[7])).unwrap();
+        assert_eq!(stats.min, Duration::from_millis(7));
+        assert_eq!(stats.median, Duration::from_millis(7));
+        assert_eq!(stats.p95, Duration::from_millis(7));
+        assert_close(stats.mean, Duration::from_millis(7));
+        assert_eq!(stats.stddev, Duration::ZERO);
+    }
+
+    #[test]
+    fn odd_count() {
+        let stats = Stats::from_samples(&millis(&[100, 3, 1, 4, 2])).unwrap();
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.p95, Duration::from_millis(100));
+        assert_close(stats.mean, Duration::from_millis(22));
+        // Variance is 7610ms^2 / 5
+        assert_close(
+            stats.stddev,
+            Duration::from_secs_f64(1522f64.sqrt() / 1000.0),
+        );
+    }
+
+    #[test]
+    fn even_count() {
+        let stats = Stats::from_samples(&millis(&[9, 4, 2, 5, 4, 7, 5, 4])).unwrap();
+        assert_eq!(stats.min, Duration::from_millis(2));
+        // Halfway between the middle two
+        assert_eq!(stats.median, Duration::from_micros(4500));
+        assert_eq!(stats.p95, Duration::from_millis(9));
+        assert_close(stats.mean, Duration::from_millis(5));
+        assert_close(stats.stddev, Duration::from_millis(2));
+    }
+
+    #[test]
+    fn nearest_rank_p95() {
+        let samples: Vec<u64> = (1..=20).rev().collect();
+        let stats = Stats::from_samples(&millis(&samples)).unwrap();
+        assert_eq!(stats.p95, Duration::from_millis(19));
+        assert_eq!(stats.median, Duration::from_micros(10500));
+        let samples: Vec<u64> = (1..=100).collect();
+        let stats = Stats::from_samples(&millis(&samples)).unwrap();
+        assert_eq!(stats.p95, Duration::from_millis(95));
+    }
+}
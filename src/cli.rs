@@ -0,0 +1,481 @@
+use crate::report::Format;
+use rustaocean2020::{common::Part, registry};
+use std::path::PathBuf;
+use std::time::Duration;
+
+pub const USAGE: &str = "\
+Usage: rustaocean2020 [OPTIONS]
+       rustaocean2020 bench [OPTIONS] [BENCH OPTIONS]
+       rustaocean2020 watch --day <DAY> [OPTIONS] [WATCH OPTIONS]
+       rustaocean2020 render --day <DAY> [OPTIONS] [RENDER OPTIONS]
+       rustaocean2020 debug [OPTIONS]
+       rustaocean2020 new <YEAR> <DAY>
+
+Commands:
+  bench                Time each phase over many runs instead of printing answers
+  watch                Re-run one day whenever its input, answers or source change
+  render               Draw the maps of days 11, 17, 20 or 24 as PBM/PPM images
+  debug                Step through the day 8 program, `help` at its prompt lists the commands
+  new                  Create and register a day from the template, with an empty input
+
+Options:
+  -y, --year <YEAR>    Event to run the days of (default: the latest one)
+  -d, --day <DAYS>     Days to run, e.g. `17` or `5,8-12` (default: all)
+  -p, --part <PART>    Only print the answer of part 1 or 2
+  -i, --input <PATH>   Read the puzzle input from PATH (single day only)
+      --stdin          Read the puzzle input from stdin (single day only)
+      --batch          Also run the named inputs in inputs/<YEAR>/dayNN/
+  -f, --format <FMT>   Output as text, json, csv or markdown (default: text)
+  -j, --jobs <N>       Solve up to N days in parallel (default: 1)
+      --record         Save answers missing from the answers files
+  -h, --help           Print this help
+
+Bench options:
+  -n, --iterations <N> Measured runs per day (default: 10)
+      --warmup <N>     Unmeasured runs before measuring (default: 2)
+
+Watch options:
+      --interval <MS>  How often to check the files for changes (default: 500)
+
+Render options:
+  -o, --out <DIR>      Directory to write the images to (default: renders)
+      --frames         Write every generation to <DIR>/<NAME>/, not just the last
+      --scale <N>      Pixels per cell side (default: 4)";
+
+const DEFAULT_ITERATIONS: usize = 10;
+const DEFAULT_WARMUP: usize = 2;
+const DEFAULT_INTERVAL_MS: usize = 500;
+const DEFAULT_RENDER_DIR: &str = "renders";
+const DEFAULT_SCALE: usize = 4;
+// The only day with a program to debug
+const DEBUG_DAY: u8 = 8;
+
+pub enum InputSource {
+    DayFile,
+    File(PathBuf),
+    Stdin,
+    Batch,
+}
+
+pub struct Args {
+    pub year: u16,
+    pub days: Vec<u8>,
+    pub part: Option<Part>,
+    pub input: InputSource,
+    pub record: bool,
+    pub jobs: usize,
+    pub format: Format,
+}
+
+pub struct BenchArgs {
+    pub iterations: usize,
+    pub warmup: usize,
+}
+
+pub struct WatchArgs {
+    pub interval: Duration,
+}
+
+pub struct RenderArgs {
+    pub out: PathBuf,
+    pub frames: bool,
+    pub scale: usize,
+}
+
+pub enum Command {
+    Run(Args),
+    Bench(Args, BenchArgs),
+    Watch(Args, WatchArgs),
+    Render(Args, RenderArgs),
+    Debug(Args),
+    New(u16, u8),
+    Help,
+}
+
+fn parse_day(txt: &str) -> Result<u8, String> {
+    txt.trim()
+        .parse::<u8>()
+        .map_err(|_| format!("invalid day `{}`", txt))
+}
+
+fn parse_year(txt: &str) -> Result<u16, String> {
+    let year = txt
+        .trim()
+        .parse::<u16>()
+        .map_err(|_| format!("invalid year `{}`", txt))?;
+    match registry::all_years().contains(&year) {
+        true => Ok(year),
+        false => Err(format!("no solvers for year {}", year)),
+    }
+}
+
+fn parse_new<I>(mut args: I) -> Result<Command, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut positional = Vec::new();
+    for arg in args.by_ref() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            _ if arg.starts_with('-') => return Err(format!("unknown argument `{}`", arg)),
+            _ => positional.push(arg),
+        }
+    }
+    let (year, day) = match positional.as_slice() {
+        [year, day] => (year, day),
+        _ => return Err("`new` takes a year and a day".to_string()),
+    };
+
+    // The first event was in 2015, and every one has 25 days
+    let year = year
+        .parse::<u16>()
+        .ok()
+        .filter(|x| (2015..=9999).contains(x))
+        .ok_or_else(|| format!("invalid year `{}`", year))?;
+    let day = parse_day(day)?;
+    if !(1..=25).contains(&day) {
+        return Err(format!("invalid day `{}`, expected 1 to 25", day));
+    }
+    Ok(Command::New(year, day))
+}
+
+// Accepts comma separated days and inclusive ranges, e.g. "5,8-12"
+fn parse_days(txt: &str) -> Result<Vec<u8>, String> {
+    let mut res = Vec::new();
+    for item in txt.split(',') {
+        let mut bounds = item.splitn(2, '-');
+        let from = parse_day(bounds.next().unwrap_or_default())?;
+        let to = match bounds.next() {
+            Some(end) => parse_day(end)?,
+            None => from,
+        };
+        if from > to {
+            return Err(format!("invalid day range `{}`", item));
+        }
+        res.extend(from..=to);
+    }
+    res.sort_unstable();
+    res.dedup();
+    Ok(res)
+}
+
+fn parse_count(flag: &str, txt: &str) -> Result<usize, String> {
+    txt.parse()
+        .map_err(|_| format!("invalid value `{}` for `{}`", txt, flag))
+}
+
+fn value_of<I>(flag: &str, args: &mut I) -> Result<String, String>
+where
+    I: Iterator<Item = String>,
+{
+    args.next()
+        .ok_or_else(|| format!("missing value for `{}`", flag))
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Run,
+    Bench,
+    Watch,
+    Render,
+    Debug,
+}
+
+pub fn parse<I>(args: I) -> Result<Command, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut args = args.peekable();
+    if args.next_if(|x| x == "new").is_some() {
+        return parse_new(args);
+    }
+    let mode = match args.peek().map(String::as_str) {
+        Some("bench") => Mode::Bench,
+        Some("watch") => Mode::Watch,
+        Some("render") => Mode::Render,
+        Some("debug") => Mode::Debug,
+        _ => Mode::Run,
+    };
+    if mode != Mode::Run {
+        args.next();
+    }
+    let mut year = None;
+    let mut days = None;
+    let mut part = None;
+    let mut input = InputSource::DayFile;
+    let mut record = false;
+    let mut jobs = None;
+    let mut format = None;
+    let mut iterations = None;
+    let mut warmup = None;
+    let mut interval = None;
+    let mut out = None;
+    let mut frames = false;
+    let mut scale = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "-y" | "--year" => year = Some(parse_year(&value_of(&arg, &mut args)?)?),
+            "-d" | "--day" => days = Some(parse_days(&value_of(&arg, &mut args)?)?),
+            "-p" | "--part" => part = Some(value_of(&arg, &mut args)?.parse()?),
+            "-i" | "--input" => input = InputSource::File(value_of(&arg, &mut args)?.into()),
+            "--stdin" => input = InputSource::Stdin,
+            "--batch" => input = InputSource::Batch,
+            "--record" => record = true,
+            "-f" | "--format" => format = Some(value_of(&arg, &mut args)?.parse()?),
+            "-j" | "--jobs" => jobs = Some(parse_count(&arg, &value_of(&arg, &mut args)?)?),
+            "-n" | "--iterations" => {
+                iterations = Some(parse_count(&arg, &value_of(&arg, &mut args)?)?)
+            }
+            "--warmup" => warmup = Some(parse_count(&arg, &value_of(&arg, &mut args)?)?),
+            "--interval" => interval = Some(parse_count(&arg, &value_of(&arg, &mut args)?)?),
+            "-o" | "--out" => out = Some(PathBuf::from(value_of(&arg, &mut args)?)),
+            "--frames" => frames = true,
+            "--scale" => scale = Some(parse_count(&arg, &value_of(&arg, &mut args)?)?),
+            _ => return Err(format!("unknown argument `{}`", arg)),
+        }
+    }
+
+    // Days can only be checked once the year is known, it may come after them
+    let year = match year.or_else(registry::latest_year) {
+        Some(year) => year,
+        None => return Err("no solvers are registered".to_string()),
+    };
+    let days = match days {
+        Some(days) => {
+            if let Some(day) = days
+                .iter()
+                .find(|&&x| registry::get_solver(year, x).is_none())
+            {
+                return Err(format!("no solver for day {} of {}", day, year));
+            }
+            days
+        }
+        None if mode == Mode::Debug => vec![DEBUG_DAY],
+        None => registry::all_days(year),
+    };
+    let single_input = matches!(input, InputSource::File(_) | InputSource::Stdin);
+    if single_input && days.len() != 1 {
+        return Err("`--input` and `--stdin` need exactly one `--day`".to_string());
+    }
+
+    // Answers are only known for the puzzle inputs in `inputs/`
+    if record && single_input {
+        return Err("`--record` can't be used with `--input` or `--stdin`".to_string());
+    }
+    if record && mode != Mode::Run {
+        return Err("`--record` only applies to running days".to_string());
+    }
+
+    // Benchmarks would only measure each other when run side by side
+    if jobs.is_some() && mode != Mode::Run {
+        return Err("`--jobs` only applies to running days".to_string());
+    }
+    if format.is_some() && mode != Mode::Run {
+        return Err("`--format` only applies to running days".to_string());
+    }
+    let jobs = jobs.unwrap_or(1);
+    if jobs == 0 {
+        return Err("`--jobs` must be at least 1".to_string());
+    }
+    if (iterations.is_some() || warmup.is_some()) && mode != Mode::Bench {
+        return Err("`--iterations` and `--warmup` only apply to `bench`".to_string());
+    }
+    if interval.is_some() && mode != Mode::Watch {
+        return Err("`--interval` only applies to `watch`".to_string());
+    }
+    if (out.is_some() || frames || scale.is_some()) && mode != Mode::Render {
+        return Err("`--out`, `--frames` and `--scale` only apply to `render`".to_string());
+    }
+
+    let args = Args {
+        year,
+        days,
+        part,
+        input,
+        record,
+        jobs,
+        format: format.unwrap_or(Format::Text),
+    };
+
+    match mode {
+        Mode::Run => Ok(Command::Run(args)),
+        Mode::Bench => {
+            let iterations = iterations.unwrap_or(DEFAULT_ITERATIONS);
+            if iterations == 0 {
+                return Err("`--iterations` must be at least 1".to_string());
+            }
+            let warmup = warmup.unwrap_or(DEFAULT_WARMUP);
+            Ok(Command::Bench(args, BenchArgs { iterations, warmup }))
+        }
+        Mode::Watch => {
+            // Only a file can change, and one day is all a terminal can follow
+            if matches!(args.input, InputSource::Stdin | InputSource::Batch) {
+                return Err("`watch` can't be used with `--stdin` or `--batch`".to_string());
+            }
+            if args.days.len() != 1 {
+                return Err("`watch` needs exactly one `--day`".to_string());
+            }
+            let interval = interval.unwrap_or(DEFAULT_INTERVAL_MS);
+            if interval == 0 {
+                return Err("`--interval` must be at least 1".to_string());
+            }
+            let interval = Duration::from_millis(interval as u64);
+            Ok(Command::Watch(args, WatchArgs { interval }))
+        }
+        Mode::Render => {
+            if matches!(args.input, InputSource::Batch) {
+                return Err("`render` can't be used with `--batch`".to_string());
+            }
+            if args.days.len() != 1 {
+                return Err("`render` needs exactly one `--day`".to_string());
+            }
+            let scale = scale.unwrap_or(DEFAULT_SCALE);
+            if scale == 0 {
+                return Err("`--scale` must be at least 1".to_string());
+            }
+            let out = out.unwrap_or_else(|| PathBuf::from(DEFAULT_RENDER_DIR));
+            Ok(Command::Render(args, RenderArgs { out, frames, scale }))
+        }
+        Mode::Debug => {
+            // Commands are read from stdin
+            if matches!(args.input, InputSource::Stdin | InputSource::Batch) {
+                return Err("`debug` can't be used with `--stdin` or `--batch`".to_string());
+            }
+            if args.days.len() != 1 {
+                return Err("`debug` needs exactly one `--day`".to_string());
+            }
+            Ok(Command::Debug(args))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_line(line: &str) -> Result<Command, String> {
+        parse(line.split_whitespace().map(str::to_string))
+    }
+
+    fn rejected(line: &str) -> String {
+        match parse_line(line) {
+            Ok(_) => panic!("`{}` was accepted", line),
+            Err(err) => err,
+        }
+    }
+
+    #[test]
+    fn day_lists() {
+        assert_eq!(parse_days("17"), Ok(vec![17]));
+        assert_eq!(parse_days("3-5"), Ok(vec![3, 4, 5]));
+        assert_eq!(parse_days("9,3-5,4"), Ok(vec![3, 4, 5, 9]));
+        assert_eq!(parse_days("7-7"), Ok(vec![7]));
+        assert_eq!(
+            parse_days("5-3"),
+            Err("invalid day range `5-3`".to_string())
+        );
+        assert_eq!(parse_days("300"), Err("invalid day `300`".to_string()));
+        assert!(parse_days("3-").is_err());
+        assert!(parse_days("a").is_err());
+        assert!(parse_days("").is_err());
+    }
+
+    #[test]
+    fn days_need_a_solver() {
+        match parse_line("-d 3-5") {
+            Ok(Command::Run(args)) => assert_eq!(args.days, vec![3, 4, 5]),
+            _ => panic!("expected a run of days 3 to 5"),
+        }
+        assert_eq!(rejected("-d 26"), "no solver for day 26 of 2020");
+        assert!(rejected("-d 0").starts_with("no solver for day 0"));
+        assert!(rejected("-y 1999").starts_with("no solvers for year"));
+    }
+
+    #[test]
+    fn single_input() {
+        assert!(parse_line("-d 3 -i in.txt").is_ok());
+        assert!(rejected("-i in.txt").contains("exactly one `--day`"));
+        assert!(rejected("-d 3-4 --stdin").contains("exactly one `--day`"));
+        assert!(rejected("-d 3 --stdin --record").contains("`--record`"));
+        assert!(rejected("-d 3 -p 3").contains("invalid part"));
+        assert!(rejected("-d").contains("missing value"));
+        assert!(rejected("--frobnicate").contains("unknown argument"));
+    }
+
+    #[test]
+    fn bench_flags() {
+        match parse_line("bench -d 1 -n 3 --warmup 0") {
+            Ok(Command::Bench(_, bench)) => assert_eq!((bench.iterations, bench.warmup), (3, 0)),
+            _ => panic!("expected a benchmark"),
+        }
+        assert!(rejected("-n 3").contains("only apply to `bench`"));
+        assert!(rejected("--warmup 1").contains("only apply to `bench`"));
+        assert!(rejected("bench -n 0").contains("at least 1"));
+        assert!(rejected("bench -j 2").contains("`--jobs` only applies"));
+        assert!(rejected("bench --record").contains("`--record` only applies"));
+        assert!(rejected("bench -f json").contains("`--format` only applies"));
+    }
+
+    #[test]
+    fn parallel_flags() {
+        match parse_line("-j 4") {
+            Ok(Command::Run(args)) => assert_eq!(args.jobs, 4),
+            _ => panic!("expected a run"),
+        }
+        assert!(rejected("-j 0").contains("at least 1"));
+        assert!(rejected("-j x").contains("invalid value `x`"));
+    }
+
+    #[test]
+    fn watch_flags() {
+        match parse_line("watch -d 3 --interval 20") {
+            Ok(Command::Watch(_, watch)) => assert_eq!(watch.interval, Duration::from_millis(20)),
+            _ => panic!("expected watch mode"),
+        }
+        assert!(rejected("watch").contains("exactly one `--day`"));
+        assert!(rejected("watch -d 3 --stdin").contains("`--stdin` or `--batch`"));
+        assert!(rejected("watch -d 3 --batch").contains("`--stdin` or `--batch`"));
+        assert!(rejected("watch -d 3 --interval 0").contains("at least 1"));
+        assert!(rejected("--interval 20").contains("only applies to `watch`"));
+    }
+
+    #[test]
+    fn render_flags() {
+        match parse_line("render -d 11 --scale 2 --frames -o out") {
+            Ok(Command::Render(_, render)) => {
+                assert_eq!(render.out, PathBuf::from("out"));
+                assert!(render.frames);
+                assert_eq!(render.scale, 2);
+            }
+            _ => panic!("expected render mode"),
+        }
+        assert!(rejected("render").contains("exactly one `--day`"));
+        assert!(rejected("render -d 11 --batch").contains("`--batch`"));
+        assert!(rejected("render -d 11 --scale 0").contains("at least 1"));
+        assert!(rejected("-d 11 --frames").contains("only apply to `render`"));
+        assert!(rejected("watch -d 11 -o out").contains("only apply to `render`"));
+    }
+
+    #[test]
+    fn debug_flags() {
+        match parse_line("debug") {
+            Ok(Command::Debug(args)) => assert_eq!(args.days, vec![DEBUG_DAY]),
+            _ => panic!("expected the debugger"),
+        }
+        assert!(rejected("debug --stdin").contains("`--stdin` or `--batch`"));
+        assert!(rejected("debug -d 1-2").contains("exactly one `--day`"));
+    }
+
+    #[test]
+    fn new_days() {
+        assert!(matches!(
+            parse_line("new 2021 7"),
+            Ok(Command::New(2021, 7))
+        ));
+        assert!(rejected("new 2021").contains("a year and a day"));
+        assert!(rejected("new 2021 26").contains("1 to 25"));
+        assert!(rejected("new 2014 1").contains("invalid year"));
+    }
+}
@@ -1,81 +1,306 @@
+use crate::error::{Error, Result};
+use regex::{Captures, Regex};
+use std::any::type_name;
+use std::fmt::{self, Display};
 use std::fs;
+use std::io::{self, Read};
 use std::path::Path;
 use std::path::PathBuf;
-use std::time::Duration;
+use std::str::FromStr;
 
-pub type Solution = ((String, String), Duration);
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl FromStr for Part {
+    type Err = String;
 
-pub fn day_input_filename(day: u8) -> PathBuf {
-    let padded_day = format!("{:02}", day);
-    Path::new("inputs").join(format!("day{}.in", padded_day))
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("invalid part `{}`, expected 1 or 2", s)),
+        }
+    }
 }
 
-pub fn get_input(path: &Path) -> Result<Vec<String>, std::io::Error> {
-    Ok(fs::read_to_string(path)
-        .expect(&format!("Input file not found: {:?}", path))
-        .lines()
-        .map(&str::to_string)
-        .collect())
+// Answer of one part. Solvers return whatever type fits the puzzle, this is
+// what they all turn into when run through the registry.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i64),
+    UInt(u64),
+    Text(String),
+    // A part without a puzzle, like the second half of day 25
+    Unit,
 }
 
-pub fn get_day_input(day: u8) -> Result<Vec<String>, std::io::Error> {
-    get_input(&day_input_filename(day))
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(x) => write!(f, "{}", x),
+            Answer::UInt(x) => write!(f, "{}", x),
+            Answer::Text(x) => write!(f, "{}", x),
+            Answer::Unit => write!(f, "-"),
+        }
+    }
 }
 
-#[macro_export]
-macro_rules! reparse_one {
-    ($err:ident, $res:expr , $($arg1:tt)::* ) => {{
-        let err = "0".parse::<$($arg1)::*>().unwrap();
-        match $res.next() {
-            Some(item) => {
-                let ret = item.unwrap().as_str().parse::<$($arg1)::*>();
-                if ret.is_err() {
-                    println!("err in parsing {:?}", item);
-                    $err = concat!("parse::", stringify!($($arg1)::*));
-                }
-                ret.unwrap_or(err)
-            }
-            _ => {
-                $err = concat!("internal ", stringify!($($arg1)::*));
-                err
+macro_rules! answer_from {
+    ($variant:ident: $($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                Answer::$variant(value as _)
             }
+        })*
+    };
+}
+
+answer_from!(Int: i8, i16, i32, i64, isize);
+answer_from!(UInt: u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<()> for Answer {
+    fn from(_: ()) -> Self {
+        Answer::Unit
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+pub fn year_input_dir(year: u16) -> PathBuf {
+    Path::new("inputs").join(year.to_string())
+}
+
+pub fn day_input_filename(year: u16, day: u8) -> PathBuf {
+    year_input_dir(year).join(format!("day{:02}.in", day))
+}
+
+// Extra inputs of a day, e.g. other people's or hand-made edge cases
+pub fn named_inputs_dir(year: u16, day: u8) -> PathBuf {
+    year_input_dir(year).join(format!("day{:02}", day))
+}
+
+// Every `NAME.in` in `dir` as (NAME, path), sorted by name. A missing
+// directory just means there are no named inputs.
+pub fn list_named_inputs(dir: &Path) -> Result<Vec<(String, PathBuf)>> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(Error::io(dir, e)),
+    };
+
+    let mut res = Vec::new();
+    for entry in entries {
+        let path = entry.map_err(|e| Error::io(dir, e))?.path();
+        if path.extension().is_none_or(|x| x != "in") || !path.is_file() {
+            continue;
+        }
+        if let Some(name) = path.file_stem().and_then(|x| x.to_str()) {
+            res.push((name.to_string(), path.clone()));
         }
-    }};
+    }
+    res.sort();
+    Ok(res)
 }
 
-#[macro_export]
-macro_rules! reparse {
-    ($txt:expr, $re:expr, $($($args:tt)::*),* ) => {
+fn split_lines(text: &str) -> Vec<String> {
+    text.lines().map(&str::to_string).collect()
+}
+
+pub fn get_input(path: &Path) -> Result<Vec<String>> {
+    let text = fs::read_to_string(path).map_err(|e| Error::io(path, e))?;
+    Ok(split_lines(&text))
+}
+
+pub fn get_stdin_input() -> Result<Vec<String>> {
+    let mut text = String::new();
+    io::stdin()
+        .read_to_string(&mut text)
+        .map_err(|e| Error::io(Path::new("<stdin>"), e))?;
+    Ok(split_lines(&text))
+}
+
+pub fn get_day_input(year: u16, day: u8) -> Result<Vec<String>> {
+    get_input(&day_input_filename(year, day))
+}
+
+// Puzzle examples are embedded in the tests as multi-line strings
+#[cfg(test)]
+pub fn example_lines(text: &str) -> Vec<String> {
+    split_lines(text)
+}
+
+pub fn parse_value<T>(txt: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    txt.parse().map_err(|e| {
+        Error::parse(format!(
+            "`{}` is not a valid {}: {}",
+            txt,
+            type_name::<T>(),
+            e
+        ))
+    })
+}
+
+// Turns the capture groups of a regex match into a typed value. Tuples of
+// `FromStr` types take the groups in order, structs can implement it with
+// `capture` for each field.
+pub trait FromCaptures: Sized {
+    fn from_captures(caps: &Captures) -> Result<Self>;
+}
+
+// Parses capture group `idx`, counted from 1 like in the pattern
+pub fn capture<T>(caps: &Captures, idx: usize) -> Result<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let text = caps
+        .get(idx)
+        .ok_or_else(|| Error::parse(format!("capture {} is missing", idx)))?
+        .as_str();
+    text.parse()
+        .map_err(|e: T::Err| Error::capture(idx, text, type_name::<T>(), e.to_string()))
+}
+
+macro_rules! tuple_from_captures {
+    ($($t:ident: $idx:tt),+) => {
+        impl<$($t),+> FromCaptures for ($($t,)+)
+        where
+            $($t: FromStr, $t::Err: Display,)+
         {
-            let mut err = "" ;
-            if let Some(captures) = $re.captures($txt) {
-                let mut matches = captures.iter();
-                matches.next();
-                let result = ($($crate::reparse_one!(err,matches,$($args)::*)),*) ;
-                if err == "" {
-                    Ok(result)
-                } else {
-                    Err(err)
-                }
-            } else {
-                Err(err)
+            fn from_captures(caps: &Captures) -> Result<Self> {
+                Ok(($(capture::<$t>(caps, $idx)?,)+))
             }
         }
     };
-    ($txt:expr, compile $re:expr, $($($args:tt)::*),* ) => {
-        {
-            let mut err = "" ;
-            let matched = Regex::new($re).unwrap().captures($txt).unwrap();
-            let mut matches = matched.iter();
-            matches.next();
-            let result = ($($crate::reparse_one!(err,matches,$($args)::*)),*) ;
-            if err == "" {
-                Ok(result)
-            } else {
-                Err(err)
-            }
+}
+
+tuple_from_captures!(A: 1);
+tuple_from_captures!(A: 1, B: 2);
+tuple_from_captures!(A: 1, B: 2, C: 3);
+tuple_from_captures!(A: 1, B: 2, C: 3, D: 4);
+tuple_from_captures!(A: 1, B: 2, C: 3, D: 4, E: 5);
+tuple_from_captures!(A: 1, B: 2, C: 3, D: 4, E: 5, F: 6);
+
+// Matches `txt` against `re` and extracts the captures, e.g.
+// `let (min, max): (usize, usize) = extract(&RANGE, "3-5")?;`
+pub fn extract<T: FromCaptures>(re: &Regex, txt: &str) -> Result<T> {
+    let caps = re
+        .captures(txt)
+        .ok_or_else(|| Error::parse(format!("`{}` doesn't match `{}`", txt, re.as_str())))?;
+    T::from_captures(&caps)
+}
+
+// Same as `extract`, for a pattern with a single capture
+pub fn extract_one<T>(re: &Regex, txt: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    extract::<(T,)>(re, txt).map(|(x,)| x)
+}
+
+// Applies `parse` to every line, tagging failures with their line number
+pub fn parse_lines<T, F>(lines: &[String], parse: F) -> Result<Vec<T>>
+where
+    F: FnMut(&str) -> Result<T>,
+{
+    parse_lines_from(lines, 1, parse)
+}
+
+// Same as `parse_lines`, for a chunk that starts at `first_line` of the input
+pub fn parse_lines_from<T, F>(lines: &[String], first_line: usize, mut parse: F) -> Result<Vec<T>>
+where
+    F: FnMut(&str) -> Result<T>,
+{
+    lines
+        .iter()
+        .enumerate()
+        .map(|(idx, line)| parse(line).map_err(|e| e.at_line(first_line + idx, line)))
+        .collect()
+}
+
+// A chunk of the input that keeps track of where it started, so errors
+// inside it still point at the right line
+#[derive(Debug, Clone, Copy)]
+pub struct Section<'a> {
+    pub first_line: usize,
+    pub lines: &'a [String],
+}
+
+impl<'a> Section<'a> {
+    pub fn parse_lines<T, F>(&self, parse: F) -> Result<Vec<T>>
+    where
+        F: FnMut(&str) -> Result<T>,
+    {
+        parse_lines_from(self.lines, self.first_line, parse)
+    }
+
+    // Splits off the first line, e.g. a `Player 1:` header
+    pub fn split_header(&self) -> Option<(&'a str, Section<'a>)> {
+        let (header, rest) = self.lines.split_first()?;
+        let body = Section {
+            first_line: self.first_line + 1,
+            lines: rest,
+        };
+        Some((header.as_str(), body))
+    }
+}
+
+fn trim_section(first_line: usize, lines: &[String]) -> Option<Section<'_>> {
+    let start = lines.iter().position(|x| !x.trim().is_empty())?;
+    let end = lines.iter().rposition(|x| !x.trim().is_empty())? + 1;
+    Some(Section {
+        first_line: first_line + start,
+        lines: &lines[start..end],
+    })
+}
+
+// Splits records separated by blank lines, several blank lines in a row
+// don't produce empty records
+pub fn split_sections(lines: &[String]) -> Vec<Section<'_>> {
+    let mut sections = Vec::new();
+    let mut start = 0;
+    for (idx, line) in lines.iter().enumerate() {
+        if line.trim().is_empty() {
+            sections.extend(trim_section(start + 1, &lines[start..idx]));
+            start = idx + 1;
         }
-    };
+    }
+    sections.extend(trim_section(start + 1, &lines[start..]));
+    sections
+}
+
+// Starts a new section at every line matching `header`, the header stays the
+// first line of its section. Anything before the first header is a section
+// of its own, blank lines around sections are dropped.
+pub fn split_by_header<'a>(lines: &'a [String], header: &Regex) -> Vec<Section<'a>> {
+    let mut sections = Vec::new();
+    let mut start = 0;
+    for (idx, line) in lines.iter().enumerate() {
+        if idx > start && header.is_match(line) {
+            sections.extend(trim_section(start + 1, &lines[start..idx]));
+            start = idx;
+        }
+    }
+    sections.extend(trim_section(start + 1, &lines[start..]));
+    sections
 }
 
 #[macro_export]
@@ -84,3 +309,107 @@ macro_rules! hashmap {
          [$(($key, $val)),*].iter().cloned().collect()
     }}
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorKind;
+
+    #[test]
+    fn extracts_captures() {
+        let re = Regex::new(r"^(\d+)-(\d+) (\w)(?: (x))?$").unwrap();
+        let (min, max, c): (usize, u8, char) = extract(&re, "1-3 a").unwrap();
+        assert_eq!((min, max, c), (1, 3, 'a'));
+        assert_eq!(extract_one::<u32>(&re, "12-3 a").unwrap(), 12);
+
+        let err = extract::<(u8, u8)>(&re, "1-300 a").unwrap_err();
+        match err.kind() {
+            ErrorKind::Capture {
+                index,
+                text,
+                target,
+                ..
+            } => assert_eq!((*index, text.as_str(), *target), (2, "300", "u8")),
+            kind => panic!("unexpected error {}", kind),
+        }
+        assert_eq!(
+            err.to_string(),
+            "parse error: capture 2 `300` is not a valid u8: number too large to fit in target type"
+        );
+
+        // An optional group that didn't take part in the match
+        let err = extract::<(u8, u8, char, char)>(&re, "1-3 a").unwrap_err();
+        assert_eq!(err.to_string(), "parse error: capture 4 is missing");
+        assert!(extract::<(u8,)>(&re, "1 a").is_err());
+    }
+
+    #[test]
+    fn answers() {
+        assert_eq!(Answer::from(-3i32), Answer::Int(-3));
+        assert_eq!(Answer::from(7usize), Answer::UInt(7));
+        assert_eq!(Answer::from("a,b"), Answer::Text("a,b".to_string()));
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(Answer::Int(-42).to_string(), "-42");
+        assert_eq!(Answer::from(()), Answer::Unit);
+    }
+
+    #[test]
+    fn named_inputs() {
+        let dir = std::env::temp_dir().join(format!("named_inputs_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        for file in ["bob.in", "alice.in", "alice.toml", "notes.txt"] {
+            fs::write(dir.join(file), "").unwrap();
+        }
+        fs::create_dir_all(dir.join("nested.in")).unwrap();
+
+        let names: Vec<String> = list_named_inputs(&dir)
+            .unwrap()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(names, vec!["alice", "bob"]);
+        assert!(list_named_inputs(&dir).unwrap().is_empty());
+    }
+
+    fn spans<'a>(sections: &[Section<'a>]) -> Vec<(usize, Vec<&'a str>)> {
+        sections
+            .iter()
+            .map(|x| (x.first_line, x.lines.iter().map(String::as_str).collect()))
+            .collect()
+    }
+
+    #[test]
+    fn sections_by_blank_lines() {
+        let lines = example_lines("\na\nb\n\n\nc\n  \nd\n");
+        assert_eq!(
+            spans(&split_sections(&lines)),
+            vec![(2, vec!["a", "b"]), (6, vec!["c"]), (8, vec!["d"])]
+        );
+        assert!(split_sections(&[]).is_empty());
+    }
+
+    #[test]
+    fn sections_by_header() {
+        let header = Regex::new(r"^Tile \d+:$").unwrap();
+        let lines = example_lines("intro\nTile 1:\n#.\n\nTile 2:\n.#\n\n");
+        assert_eq!(
+            spans(&split_by_header(&lines, &header)),
+            vec![
+                (1, vec!["intro"]),
+                (2, vec!["Tile 1:", "#."]),
+                (5, vec!["Tile 2:", ".#"])
+            ]
+        );
+    }
+
+    #[test]
+    fn section_lines_are_numbered() {
+        let lines = example_lines("1\n2\n\nPlayer 2:\n3\nx");
+        let sections = split_sections(&lines);
+        let (header, body) = sections[1].split_header().unwrap();
+        assert_eq!(header, "Player 2:");
+        let err = body.parse_lines(parse_value::<u8>).unwrap_err();
+        assert_eq!(err.line(), Some(6));
+    }
+}
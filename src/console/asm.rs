@@ -0,0 +1,240 @@
+// Assembly for the console. On top of the puzzle's `op +N` lines it has
+//
+//   ; comments           to the end of the line
+//   loop: acc +1         labels, on their own line or before an instruction
+//   jmp loop             labels as arguments, the offset to them
+//   const STEP = +4      constants, used as `acc STEP` or `acc -STEP`
+//
+// Numbers always carry their sign like in the puzzle, so a puzzle input is
+// already a valid program and `Instruction::from_str` is this parser with
+// nothing defined.
+
+use super::{Instruction, Op};
+use crate::error::{Error, Result};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashMap;
+use std::fmt::Write;
+
+lazy_static! {
+    static ref CONST: Regex = Regex::new(r"^const\s+([A-Za-z_]\w*)\s*=\s*(\S+)$").unwrap();
+    static ref LABEL: Regex = Regex::new(r"^([A-Za-z_]\w*):\s*(.*)$").unwrap();
+    static ref STATEMENT: Regex = Regex::new(r"^(\w+)\s+([+-]?)(\w+)$").unwrap();
+}
+
+#[derive(Copy, Clone, Debug)]
+enum Symbol {
+    // Index of the instruction it's in front of
+    Label(usize),
+    Const(i64),
+}
+
+type Symbols = HashMap<String, Symbol>;
+
+fn parse_number(text: &str) -> Result<i64> {
+    if !text.starts_with(['+', '-']) {
+        return Err(Error::parse(format!(
+            "number `{}` needs a sign, like `+{}`",
+            text, text
+        )));
+    }
+    text.parse()
+        .map_err(|_| Error::parse(format!("invalid number `{}`", text)))
+}
+
+// One instruction at index `idx`, with labels and constants from `symbols`
+fn parse_instruction(text: &str, symbols: &Symbols, idx: usize) -> Result<Instruction> {
+    let caps = STATEMENT
+        .captures(text)
+        .ok_or_else(|| Error::parse(format!("`{}` isn't like `op +N`", text)))?;
+    let op: Op = caps[1].parse()?;
+    let (sign, value) = (&caps[2], &caps[3]);
+    let arg = match value.starts_with(|c: char| c.is_ascii_digit()) {
+        true => parse_number(&format!("{}{}", sign, value))?,
+        false => {
+            let value = match symbols.get(value) {
+                Some(Symbol::Label(target)) => *target as i64 - idx as i64,
+                Some(Symbol::Const(value)) => *value,
+                None => {
+                    return Err(Error::parse(format!(
+                        "unknown label or constant `{}`",
+                        value
+                    )))
+                }
+            };
+            match sign {
+                "-" => value.checked_neg().ok_or_else(|| {
+                    Error::parse(format!("`{}` overflows when negated", &caps[3]))
+                })?,
+                _ => value,
+            }
+        }
+    };
+    Ok(Instruction::new(op, arg))
+}
+
+pub(super) fn parse_plain(text: &str) -> Result<Instruction> {
+    parse_instruction(text, &Symbols::new(), 0)
+}
+
+fn define(symbols: &mut Symbols, name: &str, symbol: Symbol) -> Result<()> {
+    match symbols.insert(name.to_string(), symbol) {
+        Some(_) => Err(Error::parse(format!("`{}` is defined twice", name))),
+        None => Ok(()),
+    }
+}
+
+// Labels can be used before they're defined, so the first pass only finds
+// out where they are and the second one builds the instructions
+pub fn assemble(source: &[String]) -> Result<Vec<Instruction>> {
+    let mut symbols = Symbols::new();
+    let mut statements = Vec::new();
+    for (line_idx, line) in source.iter().enumerate() {
+        let at_line = |e: Error| e.at_line(line_idx + 1, line);
+        let mut text = line.split(';').next().unwrap_or_default().trim();
+        if let Some(caps) = CONST.captures(text) {
+            let value = parse_number(&caps[2]).map_err(at_line)?;
+            define(&mut symbols, &caps[1], Symbol::Const(value)).map_err(at_line)?;
+            continue;
+        }
+        if let Some(caps) = LABEL.captures(text) {
+            let label = Symbol::Label(statements.len());
+            define(&mut symbols, &caps[1], label).map_err(at_line)?;
+            text = caps.get(2).map_or("", |x| x.as_str());
+        }
+        if !text.is_empty() {
+            statements.push((line_idx, text));
+        }
+    }
+
+    statements
+        .iter()
+        .enumerate()
+        .map(|(idx, &(line_idx, text))| {
+            parse_instruction(text, &symbols, idx)
+                .map_err(|e| e.at_line(line_idx + 1, &source[line_idx]))
+        })
+        .collect()
+}
+
+// The puzzle's format, one `op +N` per line
+pub fn emit(program: &[Instruction]) -> String {
+    program.iter().map(|x| format!("{}\n", x)).collect()
+}
+
+// A listing with a label on every instruction a jump lands on, `l<INDEX>`,
+// or `end` for the end of the program. Jumps outside of it keep their
+// offset.
+pub fn disassemble(program: &[Instruction]) -> String {
+    let end = program.len();
+    let target = |idx: usize, instr: &Instruction| match instr.op {
+        Op::Jmp => instr.next(idx).filter(|&x| x <= end),
+        Op::Nop | Op::Acc => None,
+    };
+    let mut labelled = vec![false; end + 1];
+    for (idx, instr) in program.iter().enumerate() {
+        if let Some(target) = target(idx, instr) {
+            labelled[target] = true;
+        }
+    }
+    let label = |idx: usize| match idx == end {
+        true => "end".to_string(),
+        false => format!("l{}", idx),
+    };
+
+    let mut res = String::new();
+    for (idx, &is_target) in labelled.iter().enumerate() {
+        if is_target {
+            writeln!(res, "{}:", label(idx)).unwrap();
+        }
+        if let Some(instr) = program.get(idx) {
+            match target(idx, instr) {
+                Some(target) => writeln!(res, "    {} {}", instr.op, label(target)).unwrap(),
+                None => writeln!(res, "    {}", instr).unwrap(),
+            }
+        }
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::example_lines;
+
+    static EXAMPLE: &str = "\
+nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6";
+
+    fn source(text: &str) -> Vec<String> {
+        example_lines(text)
+    }
+
+    #[test]
+    fn assembles() {
+        let program = assemble(&source(
+            "\
+; adds one, takes it back and adds it again
+const STEP = +1
+    nop +0
+loop:
+    acc STEP        ; one more
+    acc -STEP
+again: acc +STEP
+    jmp done
+    jmp loop
+done:",
+        ))
+        .unwrap();
+        assert_eq!(
+            emit(&program),
+            "nop +0\nacc +1\nacc -1\nacc +1\njmp +2\njmp -4\n"
+        );
+    }
+
+    #[test]
+    fn reports_errors() {
+        let err = |text: &str| assemble(&source(text)).unwrap_err();
+        assert_eq!(err("nop +0\njmp nowhere").line(), Some(2));
+        assert_eq!(err("a: nop +0\na: nop +0").line(), Some(2));
+        assert_eq!(err("const A = +1\nconst A = +2").line(), Some(2));
+        assert_eq!(err("const A = 1").line(), Some(1));
+        assert_eq!(err("mul +2").line(), Some(1));
+        assert_eq!(err("acc 2").line(), Some(1));
+        assert_eq!(err("nop +0\n\nacc").line(), Some(3));
+    }
+
+    #[test]
+    fn round_trips() {
+        let puzzle = source(EXAMPLE);
+        let program = assemble(&puzzle).unwrap();
+        assert_eq!(emit(&program), format!("{}\n", EXAMPLE));
+
+        // Puzzle text is assembly without labels or constants
+        let parsed: Vec<Instruction> = puzzle.iter().map(|x| x.parse().unwrap()).collect();
+        assert_eq!(parsed, program);
+
+        let listing = disassemble(&program);
+        assert_eq!(
+            listing,
+            "    nop +0\nl1:\n    acc +1\n    jmp l6\nl3:\n    acc +3\n    jmp l1\n    \
+             acc -99\nl6:\n    acc +1\n    jmp l3\n    acc +6\n"
+        );
+        let lines: Vec<String> = listing.lines().map(str::to_string).collect();
+        assert_eq!(assemble(&lines).unwrap(), program);
+
+        // Jumps to the end are labelled, jumps outside keep their offset
+        let program = assemble(&source("jmp +3\njmp +3\njmp -5")).unwrap();
+        let listing = disassemble(&program);
+        assert_eq!(listing, "    jmp end\n    jmp +3\n    jmp -5\nend:\n");
+        let lines: Vec<String> = listing.lines().map(str::to_string).collect();
+        assert_eq!(assemble(&lines).unwrap(), program);
+    }
+}
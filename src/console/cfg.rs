@@ -0,0 +1,261 @@
+use super::{Instruction, Op};
+use std::fmt::Write;
+
+// An instruction to put at `index` in place of the one there
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Patch {
+    pub index: usize,
+    pub instruction: Instruction,
+}
+
+// The control-flow graph of a program. Every instruction has at most one
+// successor, so the instructions that can reach the end form a tree rooted
+// at it, and the rest is cycles with trees hanging off them.
+pub struct Cfg {
+    program: Vec<Instruction>,
+    // The end of the program is node `program.len()`. None for jumps
+    // anywhere else outside of it.
+    successors: Vec<Option<usize>>,
+    // Entry and exit times of a walk of the tree that reaches the end, None
+    // for instructions that can't terminate
+    times: Vec<Option<(usize, usize)>>,
+}
+
+// Nops and jumps can be swapped, accumulating has no alternative
+fn swapped(instruction: Instruction) -> Option<Instruction> {
+    let op = match instruction.op {
+        Op::Nop => Op::Jmp,
+        Op::Jmp => Op::Nop,
+        Op::Acc => return None,
+    };
+    Some(Instruction::new(op, instruction.arg))
+}
+
+impl Cfg {
+    pub fn new(program: &[Instruction]) -> Self {
+        let end = program.len();
+        let successors: Vec<Option<usize>> = program
+            .iter()
+            .enumerate()
+            .map(|(idx, instr)| instr.next(idx).filter(|&x| x <= end))
+            .collect();
+
+        let mut predecessors = vec![Vec::new(); end + 1];
+        for (idx, next) in successors.iter().enumerate() {
+            if let Some(next) = next {
+                predecessors[*next].push(idx);
+            }
+        }
+
+        // Walks back from the end without recursion, a node is left once
+        // all its predecessors are
+        let mut times = vec![None; end + 1];
+        let mut clock = 0;
+        let mut stack = vec![(end, 0)];
+        times[end] = Some((0, 0));
+        while let Some((node, child)) = stack.pop() {
+            match predecessors[node].get(child) {
+                Some(&prev) => {
+                    stack.push((node, child + 1));
+                    clock += 1;
+                    times[prev] = Some((clock, 0));
+                    stack.push((prev, 0));
+                }
+                None => {
+                    clock += 1;
+                    if let Some((_, exit)) = &mut times[node] {
+                        *exit = clock;
+                    }
+                }
+            }
+        }
+
+        Cfg {
+            program: program.to_vec(),
+            successors,
+            times,
+        }
+    }
+
+    pub fn successor(&self, idx: usize) -> Option<usize> {
+        self.successors[idx]
+    }
+
+    // Whether running from `idx` ends the program, `idx` can be the end
+    pub fn can_terminate(&self, idx: usize) -> bool {
+        self.times[idx].is_some()
+    }
+
+    pub fn terminates(&self) -> bool {
+        self.can_terminate(0)
+    }
+
+    // Whether running from `from` goes through `through`
+    fn passes(&self, from: usize, through: usize) -> bool {
+        match (self.times[from], self.times[through]) {
+            (Some((entry, exit)), Some((outer_entry, outer_exit))) => {
+                outer_entry <= entry && exit <= outer_exit
+            }
+            _ => false,
+        }
+    }
+
+    // The instructions the program runs, in order, until it terminates,
+    // loops or jumps outside of it
+    pub fn execution_path(&self) -> Vec<usize> {
+        let mut seen = vec![false; self.program.len()];
+        let mut res = Vec::new();
+        let mut idx = Some(0);
+        while let Some(cur) = idx.filter(|&x| x < self.program.len() && !seen[x]) {
+            seen[cur] = true;
+            res.push(cur);
+            idx = self.successors[cur];
+        }
+        res
+    }
+
+    // Every swap of a nop and a jump that makes the program terminate, in the
+    // order the program runs into them. Only instructions on the execution
+    // path matter, and each is checked in constant time: it has to lead to
+    // the end without coming back to itself.
+    pub fn terminating_patches(&self) -> Vec<Patch> {
+        let end = self.program.len();
+        self.execution_path()
+            .into_iter()
+            .filter_map(|idx| {
+                let instruction = swapped(self.program[idx])?;
+                let next = instruction.next(idx).filter(|&x| x <= end)?;
+                match self.can_terminate(next) && !self.passes(next, idx) {
+                    true => Some(Patch {
+                        index: idx,
+                        instruction,
+                    }),
+                    false => None,
+                }
+            })
+            .collect()
+    }
+
+    // Graphviz source: instructions that can terminate are green, those the
+    // program runs have a thick border and the terminating patches are
+    // dashed red edges
+    pub fn to_dot(&self) -> String {
+        let end = self.program.len();
+        let name = |idx: usize| match idx == end {
+            true => "end".to_string(),
+            false => format!("n{}", idx),
+        };
+        let mut on_path = vec![false; end];
+        self.execution_path()
+            .into_iter()
+            .for_each(|x| on_path[x] = true);
+
+        let mut res =
+            String::from("digraph program {\n    node [shape=box, fontname=\"monospace\"];\n");
+        for (idx, instr) in self.program.iter().enumerate() {
+            write!(res, "    {} [label=\"{}: {}\"", name(idx), idx, instr).unwrap();
+            if self.can_terminate(idx) {
+                res.push_str(", style=filled, fillcolor=palegreen");
+            }
+            if on_path[idx] {
+                res.push_str(", penwidth=2");
+            }
+            res.push_str("];\n");
+        }
+        res.push_str("    end [label=\"end\", shape=doublecircle];\n");
+        if self.successors.contains(&None) {
+            res.push_str("    outside [label=\"outside\", shape=octagon];\n");
+        }
+
+        for (idx, next) in self.successors.iter().enumerate() {
+            let next = next.map_or("outside".to_string(), name);
+            writeln!(res, "    {} -> {};", name(idx), next).unwrap();
+        }
+        for patch in self.terminating_patches() {
+            let next = patch.instruction.next(patch.index).unwrap();
+            writeln!(
+                res,
+                "    {} -> {} [style=dashed, color=red, label=\"{}\"];",
+                name(patch.index),
+                name(next),
+                patch.instruction
+            )
+            .unwrap();
+        }
+        res.push_str("}\n");
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn program(lines: &[&str]) -> Vec<Instruction> {
+        lines.iter().map(|x| x.parse().unwrap()).collect()
+    }
+
+    fn example() -> Vec<Instruction> {
+        program(&[
+            "nop +0", "acc +1", "jmp +4", "acc +3", "jmp -3", "acc -99", "acc +1", "jmp -4",
+            "acc +6",
+        ])
+    }
+
+    #[test]
+    fn finds_terminating_instructions() {
+        let cfg = Cfg::new(&example());
+        assert!(!cfg.terminates());
+        let terminating: Vec<usize> = (0..=9).filter(|&x| cfg.can_terminate(x)).collect();
+        assert_eq!(terminating, vec![8, 9]);
+        assert_eq!(cfg.execution_path(), vec![0, 1, 2, 6, 7, 3, 4]);
+        assert_eq!(cfg.successor(7), Some(3));
+    }
+
+    #[test]
+    fn finds_all_patches() {
+        let cfg = Cfg::new(&example());
+        let patch = Patch {
+            index: 7,
+            instruction: Instruction::new(Op::Nop, -4),
+        };
+        assert_eq!(cfg.terminating_patches(), vec![patch]);
+
+        // Both the second nop and the jump back can be fixed, the first nop
+        // would jump out of the program
+        let cfg = Cfg::new(&program(&[
+            "nop +9", "nop +3", "acc +1", "jmp -2", "acc +1",
+        ]));
+        let indices: Vec<usize> = cfg.terminating_patches().iter().map(|x| x.index).collect();
+        assert_eq!(indices, vec![1, 3]);
+    }
+
+    #[test]
+    fn patches_must_not_come_back() {
+        // Already terminates, and swapping either jump makes it loop through
+        // the patched instruction
+        let cfg = Cfg::new(&program(&["jmp +2", "jmp -1", "nop -1"]));
+        assert!(cfg.terminates());
+        assert_eq!(cfg.terminating_patches(), vec![]);
+    }
+
+    #[test]
+    fn exports_dot() {
+        let dot = Cfg::new(&program(&["jmp +2", "jmp +5", "jmp -2", "acc +1"])).to_dot();
+        let lines: Vec<&str> = dot.lines().collect();
+        assert_eq!(lines[0], "digraph program {");
+        assert_eq!(lines[2], "    n0 [label=\"0: jmp +2\", penwidth=2];");
+        assert_eq!(lines[3], "    n1 [label=\"1: jmp +5\"];");
+        assert_eq!(
+            lines[5],
+            "    n3 [label=\"3: acc +1\", style=filled, fillcolor=palegreen];"
+        );
+        assert!(lines.contains(&"    n1 -> outside;"));
+        assert!(lines.contains(&"    n3 -> end;"));
+        assert_eq!(
+            lines[lines.len() - 2],
+            "    n2 -> n3 [style=dashed, color=red, label=\"nop -2\"];"
+        );
+        assert_eq!(lines[lines.len() - 1], "}");
+    }
+}
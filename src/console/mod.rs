@@ -0,0 +1,122 @@
+// The handheld game console of 2020 day 8. A program is a list of
+// instructions, each an operation and a signed argument, e.g. `jmp -3`.
+// The console has one register, the accumulator, which starts at 0.
+//
+//   nop +N  does nothing, goes on with the next instruction
+//   acc +N  adds N to the accumulator, goes on with the next instruction
+//   jmp +N  goes on with the instruction N away from this one
+//
+// A program terminates when it tries to run the instruction right after its
+// last one. Jumping anywhere else outside of it is an error.
+//
+// A new operation needs a variant in `Op`, its name in `Op::name`, where it
+// goes next in `Instruction::next` and its effect in `Vm::step`. Parsing and
+// printing pick it up from the name, the control-flow graph from `next`.
+
+pub mod asm;
+mod cfg;
+mod vm;
+
+pub use asm::{assemble, disassemble};
+pub use cfg::{Cfg, Patch};
+pub use vm::{Stop, TraceEntry, Vm};
+
+use crate::error::{Error, Result};
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum Op {
+    Nop,
+    Acc,
+    Jmp,
+}
+
+impl Op {
+    pub const ALL: [Op; 3] = [Op::Nop, Op::Acc, Op::Jmp];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Op::Nop => "nop",
+            Op::Acc => "acc",
+            Op::Jmp => "jmp",
+        }
+    }
+}
+
+impl FromStr for Op {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Op::ALL
+            .iter()
+            .copied()
+            .find(|x| x.name() == s)
+            .ok_or_else(|| Error::parse(format!("unknown instruction `{}`", s)))
+    }
+}
+
+impl fmt::Display for Op {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Instruction {
+    pub op: Op,
+    pub arg: i64,
+}
+
+impl Instruction {
+    pub fn new(op: Op, arg: i64) -> Self {
+        Instruction { op, arg }
+    }
+
+    // The instruction to run after this one at `pc`, None when the program
+    // counter would overflow
+    pub fn next(self, pc: usize) -> Option<usize> {
+        match self.op {
+            Op::Jmp => isize::try_from(self.arg)
+                .ok()
+                .and_then(|x| pc.checked_add_signed(x)),
+            Op::Nop | Op::Acc => pc.checked_add(1),
+        }
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        asm::parse_plain(s)
+    }
+}
+
+// The way the puzzle writes it, the argument always has a sign
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {:+}", self.op, self.arg)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn instructions() {
+        let instr: Instruction = "jmp -3".parse().unwrap();
+        assert_eq!(instr, Instruction::new(Op::Jmp, -3));
+        assert_eq!(instr.to_string(), "jmp -3");
+        assert_eq!(Instruction::new(Op::Nop, 0).to_string(), "nop +0");
+        assert!("jmp 3".parse::<Instruction>().is_err());
+        assert!("mul +3".parse::<Instruction>().is_err());
+        assert!("acc +99999999999999999999".parse::<Instruction>().is_err());
+
+        assert_eq!(instr.next(5), Some(2));
+        assert_eq!(instr.next(2), None);
+        assert_eq!(Instruction::new(Op::Acc, -3).next(2), Some(3));
+    }
+}
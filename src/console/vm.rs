@@ -0,0 +1,276 @@
+use super::{Instruction, Op};
+use crate::error::{Error, Result};
+use std::collections::BTreeSet;
+use std::fmt;
+
+// Why `Vm::run` stopped
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Stop {
+    // Reached the end of the program
+    Halted,
+    // About to run an instruction for the second time since the last reset
+    Loop(usize),
+    // About to run an instruction with a breakpoint
+    Breakpoint(usize),
+}
+
+// One executed instruction, with the accumulator after it
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TraceEntry {
+    pub step: u64,
+    pub pc: usize,
+    pub instruction: Instruction,
+    pub acc: i64,
+}
+
+impl fmt::Display for TraceEntry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "#{:<6} {:>5}: {:<10} acc={}",
+            self.step,
+            self.pc,
+            self.instruction.to_string(),
+            self.acc
+        )
+    }
+}
+
+pub struct Vm {
+    program: Vec<Instruction>,
+    pc: usize,
+    acc: i64,
+    steps: u64,
+    visited: Vec<bool>,
+    breakpoints: BTreeSet<usize>,
+    trace: Option<Vec<TraceEntry>>,
+}
+
+impl Vm {
+    pub fn new(program: Vec<Instruction>) -> Self {
+        let len = program.len();
+        Vm {
+            program,
+            pc: 0,
+            acc: 0,
+            steps: 0,
+            visited: vec![false; len],
+            breakpoints: BTreeSet::new(),
+            trace: None,
+        }
+    }
+
+    pub fn program(&self) -> &[Instruction] {
+        &self.program
+    }
+
+    pub fn pc(&self) -> usize {
+        self.pc
+    }
+
+    pub fn acc(&self) -> i64 {
+        self.acc
+    }
+
+    pub fn steps(&self) -> u64 {
+        self.steps
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.pc == self.program.len()
+    }
+
+    // Back to the start, keeping patches and breakpoints
+    pub fn reset(&mut self) {
+        self.pc = 0;
+        self.acc = 0;
+        self.steps = 0;
+        self.visited.iter_mut().for_each(|x| *x = false);
+        if let Some(trace) = &mut self.trace {
+            trace.clear();
+        }
+    }
+
+    fn check_index(&self, idx: usize) -> Result<()> {
+        match idx < self.program.len() {
+            true => Ok(()),
+            false => Err(Error::solve(format!(
+                "instruction {} is outside the program of {}",
+                idx,
+                self.program.len()
+            ))),
+        }
+    }
+
+    // Replaces an instruction and returns the one it replaced
+    pub fn patch(&mut self, idx: usize, instruction: Instruction) -> Result<Instruction> {
+        self.check_index(idx)?;
+        Ok(std::mem::replace(&mut self.program[idx], instruction))
+    }
+
+    // False when there already was one
+    pub fn set_breakpoint(&mut self, idx: usize) -> Result<bool> {
+        self.check_index(idx)?;
+        Ok(self.breakpoints.insert(idx))
+    }
+
+    pub fn clear_breakpoint(&mut self, idx: usize) -> bool {
+        self.breakpoints.remove(&idx)
+    }
+
+    pub fn breakpoints(&self) -> impl Iterator<Item = usize> + '_ {
+        self.breakpoints.iter().copied()
+    }
+
+    // Records every instruction run from now on
+    pub fn enable_trace(&mut self) {
+        self.trace.get_or_insert_with(Vec::new);
+    }
+
+    pub fn trace(&self) -> &[TraceEntry] {
+        self.trace.as_deref().unwrap_or(&[])
+    }
+
+    // Runs the instruction at the program counter
+    pub fn step(&mut self) -> Result<()> {
+        let pc = self.pc;
+        let instruction = *self
+            .program
+            .get(pc)
+            .ok_or_else(|| Error::solve("the program has already halted"))?;
+        let overflow =
+            |what: &str| Error::solve(format!("{} overflow at instruction {}", what, pc));
+
+        let next = instruction
+            .next(pc)
+            .ok_or_else(|| overflow("program counter"))?;
+        match instruction.op {
+            Op::Nop | Op::Jmp => {}
+            Op::Acc => {
+                self.acc = self
+                    .acc
+                    .checked_add(instruction.arg)
+                    .ok_or_else(|| overflow("accumulator"))?;
+            }
+        }
+        if next > self.program.len() {
+            return Err(Error::solve(format!(
+                "instruction {} jumps to {}, outside the program of {}",
+                pc,
+                next,
+                self.program.len()
+            )));
+        }
+
+        self.visited[pc] = true;
+        self.pc = next;
+        self.steps += 1;
+        if let Some(trace) = &mut self.trace {
+            trace.push(TraceEntry {
+                step: self.steps,
+                pc,
+                instruction,
+                acc: self.acc,
+            });
+        }
+        Ok(())
+    }
+
+    // Steps until the program halts, loops or hits a breakpoint. The
+    // instruction it starts at always runs, so it can resume from a stop.
+    pub fn run(&mut self) -> Result<Stop> {
+        loop {
+            if self.is_halted() {
+                return Ok(Stop::Halted);
+            }
+            self.step()?;
+            if self.is_halted() {
+                return Ok(Stop::Halted);
+            }
+            if self.visited[self.pc] {
+                return Ok(Stop::Loop(self.pc));
+            }
+            if self.breakpoints.contains(&self.pc) {
+                return Ok(Stop::Breakpoint(self.pc));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::{example_lines, parse_lines};
+
+    fn vm(text: &str) -> Vm {
+        Vm::new(parse_lines(&example_lines(text), str::parse).unwrap())
+    }
+
+    static LOOPING: &str = "\
+nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6";
+
+    #[test]
+    fn runs_until_loop() {
+        let mut vm = vm(LOOPING);
+        assert_eq!(vm.run().unwrap(), Stop::Loop(1));
+        assert_eq!(vm.acc(), 5);
+        assert_eq!(vm.steps(), 7);
+    }
+
+    #[test]
+    fn patches_and_halts() {
+        let mut vm = vm(LOOPING);
+        let old = vm.patch(7, Instruction::new(Op::Nop, -4)).unwrap();
+        assert_eq!(old, Instruction::new(Op::Jmp, -4));
+        assert_eq!(vm.run().unwrap(), Stop::Halted);
+        assert_eq!(vm.acc(), 8);
+        assert!(vm.is_halted());
+        assert!(vm.step().is_err());
+        assert!(vm.patch(9, old).is_err());
+
+        vm.reset();
+        assert_eq!((vm.pc(), vm.acc(), vm.steps()), (0, 0, 0));
+        assert_eq!(vm.program()[7], Instruction::new(Op::Nop, -4));
+    }
+
+    #[test]
+    fn breakpoints_and_trace() {
+        let mut vm = vm(LOOPING);
+        vm.enable_trace();
+        assert!(vm.set_breakpoint(6).unwrap());
+        assert!(!vm.set_breakpoint(6).unwrap());
+        assert!(vm.set_breakpoint(20).is_err());
+        assert_eq!(vm.run().unwrap(), Stop::Breakpoint(6));
+        assert_eq!(vm.breakpoints().collect::<Vec<usize>>(), vec![6]);
+        assert!(vm.clear_breakpoint(6));
+
+        vm.step().unwrap();
+        let pcs: Vec<usize> = vm.trace().iter().map(|x| x.pc).collect();
+        assert_eq!(pcs, vec![0, 1, 2, 6]);
+        assert_eq!(vm.trace()[3].acc, 2);
+        assert_eq!(vm.trace()[3].to_string(), "#4          6: acc +1     acc=2");
+    }
+
+    #[test]
+    fn reports_overflow() {
+        let mut acc = vm("acc +9223372036854775807\nacc +1");
+        acc.step().unwrap();
+        assert!(acc.step().is_err());
+        assert_eq!(acc.acc(), i64::MAX);
+
+        let mut pc = vm("nop +0\njmp -2");
+        pc.step().unwrap();
+        assert!(pc.step().is_err());
+        assert_eq!(pc.pc(), 1);
+        assert!(vm("jmp +2").run().is_err());
+        assert!(vm("jmp +1").run().is_ok());
+    }
+}
@@ -0,0 +1,42 @@
+use crate::common::{parse_lines, parse_value};
+use crate::error::Result;
+use crate::solver::Solver;
+
+pub struct Day0x;
+
+impl Solver for Day0x {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 0;
+    type Input = Vec<String>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(&self, raw_input: &[String]) -> Result<Self::Input> {
+        parse_lines(raw_input, parse_value)
+    }
+
+    fn part1(&self, _input: &Self::Input) -> Result<Self::Answer1> {
+        Ok(0)
+    }
+
+    fn part2(&self, _input: &Self::Input) -> Result<Self::Answer2> {
+        Ok(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::example_lines;
+
+    static EXAMPLE: &str = "\
+";
+
+    #[test]
+    #[ignore = "example not filled in yet"]
+    fn example() {
+        let input = Day0x.parse(&example_lines(EXAMPLE)).unwrap();
+        assert_eq!(Day0x.part1(&input).unwrap(), 0);
+        assert_eq!(Day0x.part2(&input).unwrap(), 0);
+    }
+}
@@ -0,0 +1,313 @@
+use crate::cli::Args;
+use rustaocean2020::console::{Cfg, Instruction, Stop, Vm};
+use rustaocean2020::error::{Error, Result};
+use rustaocean2020::solver::Solver;
+use rustaocean2020::y2020::day08::Day08;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+
+const HELP: &str = "\
+step [N]            Run the next N instructions (default: 1)
+continue            Run until the program halts, loops or hits a breakpoint
+print acc|pc|steps  Print a register
+print <IDX>         Print the instruction at IDX
+break <IDX>         Stop before running the instruction at IDX
+delete <IDX>        Remove the breakpoint at IDX
+patch <IDX> <INSTR> Replace the instruction at IDX, e.g. `patch 42 nop +0`
+list [IDX]          Show the instructions around IDX (default: the current one)
+trace [N]           Show the last N instructions run (default: 10)
+patches             List every swap of a nop and a jmp that makes the program terminate
+dot <PATH>          Write the control-flow graph to PATH as Graphviz DOT
+reset               Start over, keeping patches and breakpoints
+quit                Leave the debugger";
+
+// Instructions shown on each side of the current one by `list`
+const LIST_CONTEXT: usize = 4;
+const DEFAULT_TRACE: usize = 10;
+
+// The commands of the REPL, apart from reading and printing, so they can be
+// tested without a terminal
+pub struct Debugger {
+    vm: Vm,
+}
+
+fn parse_index(arg: Option<&str>) -> Result<usize> {
+    let arg = arg.ok_or_else(|| Error::parse("missing instruction index"))?;
+    arg.parse()
+        .map_err(|_| Error::parse(format!("invalid instruction index `{}`", arg)))
+}
+
+fn parse_count(arg: Option<&str>, default: usize) -> Result<usize> {
+    match arg {
+        Some(arg) => arg
+            .parse()
+            .map_err(|_| Error::parse(format!("invalid count `{}`", arg))),
+        None => Ok(default),
+    }
+}
+
+impl Debugger {
+    pub fn new(program: Vec<Instruction>) -> Self {
+        let mut vm = Vm::new(program);
+        vm.enable_trace();
+        Debugger { vm }
+    }
+
+    fn location(&self) -> String {
+        match self.vm.program().get(self.vm.pc()) {
+            Some(instr) => format!("at {}: {} (acc={})", self.vm.pc(), instr, self.vm.acc()),
+            None => format!("halted (acc={})", self.vm.acc()),
+        }
+    }
+
+    fn list(&self, around: usize) -> String {
+        let from = around.saturating_sub(LIST_CONTEXT);
+        let to = (around + LIST_CONTEXT + 1).min(self.vm.program().len());
+        let breakpoints: Vec<usize> = self.vm.breakpoints().collect();
+        (from..to)
+            .map(|idx| {
+                let marker = if idx == self.vm.pc() { '>' } else { ' ' };
+                let bp = if breakpoints.contains(&idx) { '*' } else { ' ' };
+                format!("{}{} {:>5}: {}", marker, bp, idx, self.vm.program()[idx])
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    fn trace(&self, count: usize) -> String {
+        let trace = self.vm.trace();
+        trace[trace.len().saturating_sub(count)..]
+            .iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    // Runs one line of input and returns what to print, None to quit
+    pub fn execute(&mut self, line: &str) -> Result<Option<String>> {
+        let mut words = line.split_whitespace();
+        let command = match words.next() {
+            Some(command) => command,
+            None => return Ok(Some(String::new())),
+        };
+        let arg = words.next();
+        let out = match command {
+            "s" | "step" => {
+                let count = parse_count(arg, 1)?;
+                let before = self.vm.trace().len();
+                for _ in 0..count {
+                    if self.vm.is_halted() {
+                        break;
+                    }
+                    self.vm.step()?;
+                }
+                let ran = self.vm.trace().len() - before;
+                match ran {
+                    0 => self.location(),
+                    _ => format!("{}\n{}", self.trace(ran), self.location()),
+                }
+            }
+            "c" | "continue" => {
+                let reason = match self.vm.run()? {
+                    Stop::Halted => "program terminated".to_string(),
+                    Stop::Loop(idx) => format!("instruction {} would run a second time", idx),
+                    Stop::Breakpoint(idx) => format!("breakpoint at {}", idx),
+                };
+                format!("{}, {}", reason, self.location())
+            }
+            "p" | "print" => match arg {
+                Some("acc") => self.vm.acc().to_string(),
+                Some("pc") => self.vm.pc().to_string(),
+                Some("steps") => self.vm.steps().to_string(),
+                _ => {
+                    let idx = parse_index(arg)?;
+                    self.vm
+                        .program()
+                        .get(idx)
+                        .ok_or_else(|| Error::parse(format!("no instruction {}", idx)))?
+                        .to_string()
+                }
+            },
+            "b" | "break" => {
+                let idx = parse_index(arg)?;
+                match self.vm.set_breakpoint(idx)? {
+                    true => format!("breakpoint at {}", idx),
+                    false => format!("already a breakpoint at {}", idx),
+                }
+            }
+            "d" | "delete" => {
+                let idx = parse_index(arg)?;
+                match self.vm.clear_breakpoint(idx) {
+                    true => format!("deleted breakpoint at {}", idx),
+                    false => format!("no breakpoint at {}", idx),
+                }
+            }
+            "patch" => {
+                let idx = parse_index(arg)?;
+                let instr: Instruction = words.collect::<Vec<&str>>().join(" ").parse()?;
+                let old = self.vm.patch(idx, instr)?;
+                format!("{}: {} -> {}", idx, old, instr)
+            }
+            "l" | "list" => {
+                let around = match arg {
+                    Some(_) => parse_index(arg)?,
+                    None => self.vm.pc(),
+                };
+                self.list(around)
+            }
+            "t" | "trace" => self.trace(parse_count(arg, DEFAULT_TRACE)?),
+            "patches" => {
+                let cfg = Cfg::new(self.vm.program());
+                let patches: Vec<String> = cfg
+                    .terminating_patches()
+                    .iter()
+                    .map(|x| format!("patch {} {}", x.index, x.instruction))
+                    .collect();
+                if cfg.terminates() {
+                    "the program already terminates".to_string()
+                } else if patches.is_empty() {
+                    "no single patch makes the program terminate".to_string()
+                } else {
+                    patches.join("\n")
+                }
+            }
+            "dot" => {
+                let path = Path::new(arg.ok_or_else(|| Error::parse("missing path"))?);
+                let dot = Cfg::new(self.vm.program()).to_dot();
+                fs::write(path, dot).map_err(|e| Error::io(path, e))?;
+                format!("wrote {}", path.display())
+            }
+            "reset" => {
+                self.vm.reset();
+                self.location()
+            }
+            "h" | "help" => HELP.to_string(),
+            "q" | "quit" => return Ok(None),
+            _ => {
+                return Err(Error::parse(format!(
+                    "unknown command `{}`, `help` lists them",
+                    command
+                )))
+            }
+        };
+        Ok(Some(out))
+    }
+}
+
+// Reads commands from stdin until `quit` or the end of input
+pub fn debug(args: &Args) -> Result<()> {
+    let day = args.days[0];
+    if (args.year, day) != (2020, 8) {
+        return Err(Error::solve("only 2020/8 runs on the handheld console"));
+    }
+    let input = crate::load_input(args.year, day, &args.input)?;
+    let mut debugger = Debugger::new(Day08.parse(&input)?);
+    println!(
+        "loaded {} instructions, `help` lists the commands\n{}",
+        input.len(),
+        debugger.location()
+    );
+
+    let stdin = io::stdin();
+    let io_err = |e| Error::io(Path::new("<stdin>"), e);
+    let mut line = String::new();
+    loop {
+        print!("(vm) ");
+        io::stdout()
+            .flush()
+            .map_err(|e| Error::io(Path::new("<stdout>"), e))?;
+        line.clear();
+        if stdin.lock().read_line(&mut line).map_err(io_err)? == 0 {
+            println!();
+            return Ok(());
+        }
+        match debugger.execute(&line) {
+            Ok(Some(out)) if out.is_empty() => {}
+            Ok(Some(out)) => println!("{}", out),
+            Ok(None) => return Ok(()),
+            Err(err) => eprintln!("error: {}", err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn debugger() -> Debugger {
+        let program = [
+            "nop +0", "acc +1", "jmp +4", "acc +3", "jmp -3", "acc -99", "acc +1", "jmp -4",
+            "acc +6",
+        ];
+        Debugger::new(program.iter().map(|x| x.parse().unwrap()).collect())
+    }
+
+    fn run(debugger: &mut Debugger, line: &str) -> String {
+        debugger.execute(line).unwrap().unwrap()
+    }
+
+    #[test]
+    fn steps_and_continues() {
+        let mut dbg = debugger();
+        let stepped = [
+            "#1          0: nop +0     acc=0",
+            "#2          1: acc +1     acc=1",
+            "at 2: jmp +4 (acc=1)",
+        ];
+        assert_eq!(run(&mut dbg, "step 2"), stepped.join("\n"));
+        assert_eq!(run(&mut dbg, "break 7"), "breakpoint at 7");
+        assert_eq!(run(&mut dbg, "c"), "breakpoint at 7, at 7: jmp -4 (acc=2)");
+        assert_eq!(
+            run(&mut dbg, "continue"),
+            "instruction 1 would run a second time, at 1: acc +1 (acc=5)"
+        );
+        assert_eq!(run(&mut dbg, "print acc"), "5");
+        assert_eq!(run(&mut dbg, "p steps"), "7");
+        assert_eq!(run(&mut dbg, "trace 1"), "#7          4: jmp -3     acc=5");
+    }
+
+    #[test]
+    fn patches() {
+        let mut dbg = debugger();
+        assert_eq!(run(&mut dbg, "patches"), "patch 7 nop -4");
+        assert_eq!(run(&mut dbg, "patch 7 nop +0"), "7: jmp -4 -> nop +0");
+        assert_eq!(run(&mut dbg, "patches"), "the program already terminates");
+        assert_eq!(run(&mut dbg, "print 7"), "nop +0");
+        assert_eq!(
+            run(&mut dbg, "continue"),
+            "program terminated, halted (acc=8)"
+        );
+        assert_eq!(run(&mut dbg, "step"), "halted (acc=8)");
+        assert_eq!(run(&mut dbg, "reset"), "at 0: nop +0 (acc=0)");
+        assert_eq!(run(&mut dbg, "b 1"), "breakpoint at 1");
+        let listed = [
+            ">      0: nop +0",
+            " *     1: acc +1",
+            "       2: jmp +4",
+            "       3: acc +3",
+            "       4: jmp -3",
+            "       5: acc -99",
+            "       6: acc +1",
+        ];
+        assert_eq!(run(&mut dbg, "list 2"), listed.join("\n"));
+        assert_eq!(dbg.execute("quit").unwrap(), None);
+    }
+
+    #[test]
+    fn rejects_bad_commands() {
+        let mut dbg = debugger();
+        for line in &[
+            "jump",
+            "patch 7 mul +2",
+            "patch 9 nop +0",
+            "print 20",
+            "break x",
+            "step -1",
+            "dot",
+        ] {
+            assert!(dbg.execute(line).is_err(), "{}", line);
+        }
+        assert_eq!(run(&mut dbg, ""), "");
+    }
+}
@@ -0,0 +1,135 @@
+use std::{fmt, io, path::Path};
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug)]
+pub enum ErrorKind {
+    Io(io::Error),
+    Parse(String),
+    // A regex capture group that didn't parse into the type it was extracted as
+    Capture {
+        index: usize,
+        text: String,
+        target: &'static str,
+        reason: String,
+    },
+    Solve(String),
+}
+
+// Where an error happened is filled in on the way up: the parser knows the line,
+// the runner knows the day.
+#[derive(Debug)]
+pub struct Error {
+    kind: ErrorKind,
+    day: Option<u8>,
+    line: Option<usize>,
+    text: Option<String>,
+}
+
+impl Error {
+    fn new(kind: ErrorKind) -> Self {
+        Error {
+            kind,
+            day: None,
+            line: None,
+            text: None,
+        }
+    }
+
+    pub fn io(path: &Path, err: io::Error) -> Self {
+        Error::new(ErrorKind::Io(err)).with_text(&path.display().to_string())
+    }
+
+    pub fn parse<M: Into<String>>(msg: M) -> Self {
+        Error::new(ErrorKind::Parse(msg.into()))
+    }
+
+    pub fn capture(index: usize, text: &str, target: &'static str, reason: String) -> Self {
+        Error::new(ErrorKind::Capture {
+            index,
+            text: text.to_string(),
+            target,
+            reason,
+        })
+    }
+
+    pub fn solve<M: Into<String>>(msg: M) -> Self {
+        Error::new(ErrorKind::Solve(msg.into()))
+    }
+
+    pub fn with_text(mut self, text: &str) -> Self {
+        self.text = Some(text.to_string());
+        self
+    }
+
+    // Line numbers are 1-based, like in an editor
+    pub fn at_line(mut self, line: usize, text: &str) -> Self {
+        self.line = Some(line);
+        self.with_text(text)
+    }
+
+    pub fn for_day(mut self, day: u8) -> Self {
+        self.day = Some(day);
+        self
+    }
+
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+
+    pub fn day(&self) -> Option<u8> {
+        self.day
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    pub fn text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ErrorKind::Io(err) => write!(f, "{}", err),
+            ErrorKind::Parse(msg) => write!(f, "parse error: {}", msg),
+            ErrorKind::Capture {
+                index,
+                text,
+                target,
+                reason,
+            } => write!(
+                f,
+                "parse error: capture {} `{}` is not a valid {}: {}",
+                index, text, target, reason
+            ),
+            ErrorKind::Solve(msg) => write!(f, "no solution: {}", msg),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day{:02}: ", day)?;
+        }
+        if let Some(line) = self.line {
+            write!(f, "line {}: ", line)?;
+        }
+        if let Some(text) = &self.text {
+            write!(f, "`{}`: ", text)?;
+        }
+        write!(f, "{}", self.kind)
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            ErrorKind::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
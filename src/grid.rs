@@ -0,0 +1,368 @@
+use crate::error::{Error, Result};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+// Steps to the 4 orthogonal and all 8 surrounding cells, as (dx, dy)
+pub const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+pub const ADJACENT: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+// Rectangular map of cells, stored row by row. Positions are (x, y) with
+// (0, 0) in the top left corner, like the puzzle inputs they're parsed from.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+
+    // Every cell is `cell((x, y))`
+    pub fn from_fn<F>(width: usize, height: usize, mut cell: F) -> Self
+    where
+        F: FnMut((usize, usize)) -> T,
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut cell)
+            .collect();
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let width = match rows.first() {
+            Some(row) if row.is_empty() => return Err(Error::parse("row 1 is empty")),
+            Some(row) => row.len(),
+            None => return Err(Error::parse("empty map")),
+        };
+        if let Some(idx) = rows.iter().position(|x| x.len() != width) {
+            return Err(Error::parse(format!(
+                "row {} has {} cells instead of {}",
+                idx + 1,
+                rows[idx].len(),
+                width
+            )));
+        }
+        let height = rows.len();
+        Ok(Grid {
+            cells: rows.into_iter().flatten().collect(),
+            width,
+            height,
+        })
+    }
+
+    // Parses a character map, `cell` returns None for characters that don't
+    // belong on it. Every row must be as wide as the first one.
+    pub fn parse<F>(lines: &[String], cell: F) -> Result<Self>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        Grid::parse_from(lines, 1, cell)
+    }
+
+    // Same as `parse`, for a map that starts at `first_line` of the input
+    pub fn parse_from<F>(lines: &[String], first_line: usize, mut cell: F) -> Result<Self>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let width = match lines.first() {
+            Some(row) => row.chars().count(),
+            None => return Err(Error::parse("empty map")),
+        };
+
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for (idx, row) in lines.iter().enumerate() {
+            let line_err = |msg: String| Error::parse(msg).at_line(first_line + idx, row);
+            if row.is_empty() {
+                return Err(line_err("empty row".to_string()));
+            }
+            let before = cells.len();
+            for c in row.chars() {
+                let value = cell(c).ok_or_else(|| line_err(format!("unexpected cell `{}`", c)))?;
+                cells.push(value);
+            }
+            if cells.len() - before != width {
+                return Err(line_err(format!("expected {} columns", width)));
+            }
+        }
+        Ok(Grid {
+            cells,
+            width,
+            height: lines.len(),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn idx(&self, x: usize, y: usize) -> Option<usize> {
+        if x < self.width && y < self.height {
+            Some(x + y * self.width)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.idx(x, y).map(|idx| &self.cells[idx])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.idx(x, y).map(move |idx| &mut self.cells[idx])
+    }
+
+    // Treats the map as repeating in every direction
+    pub fn get_wrapping(&self, x: isize, y: isize) -> &T {
+        let x = x.rem_euclid(self.width as isize) as usize;
+        let y = y.rem_euclid(self.height as isize) as usize;
+        &self[(x, y)]
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, value: T) {
+        self[(x, y)] = value;
+    }
+
+    // The position one `(dx, dy)` step away, None when that's off the map
+    pub fn step(&self, (x, y): (usize, usize), (dx, dy): (isize, isize)) -> Option<(usize, usize)> {
+        let x = x.checked_add_signed(dx)?;
+        let y = y.checked_add_signed(dy)?;
+        self.idx(x, y).map(|_| (x, y))
+    }
+
+    pub fn neighbours4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ORTHOGONAL
+            .iter()
+            .filter_map(move |&dir| self.step(pos, dir))
+    }
+
+    pub fn neighbours8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ADJACENT.iter().filter_map(move |&dir| self.step(pos, dir))
+    }
+
+    // Every position in direction `dir` from `pos` (not including it), until the edge
+    pub fn ray(
+        &self,
+        pos: (usize, usize),
+        dir: (isize, isize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let mut curr = Some(pos);
+        std::iter::from_fn(move || {
+            curr = self.step(curr?, dir);
+            curr
+        })
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on 0, an empty grid has no rows either way
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(x < self.width, "column {} out of {}", x, self.width);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    // Turned a quarter clockwise, so the left column becomes the top row
+    pub fn rotate_right(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width)
+            .flat_map(|x| (0..self.height).rev().map(move |y| (x, y)))
+            .map(|(x, y)| self[(x, y)].clone())
+            .collect();
+        Grid {
+            cells,
+            width: self.height,
+            height: self.width,
+        }
+    }
+
+    // Mirrored top to bottom
+    pub fn flip_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..self.height)
+            .rev()
+            .flat_map(|y| self.row(y).iter().cloned())
+            .collect();
+        Grid {
+            cells,
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, func: F) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(func).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    // One line per row, e.g. `grid.render(|&x| if x { '#' } else { '.' })`
+    pub fn render<F: FnMut(&T) -> char>(&self, mut cell: F) -> String {
+        let mut res = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            res.extend(row.iter().map(&mut cell));
+            res.push('\n');
+        }
+        res
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        match self.idx(x, y) {
+            Some(idx) => &self.cells[idx],
+            None => panic!(
+                "({}, {}) is outside of {}x{}",
+                x, y, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        match self.idx(x, y) {
+            Some(idx) => &mut self.cells[idx],
+            None => panic!(
+                "({}, {}) is outside of {}x{}",
+                x, y, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::example_lines;
+
+    fn digits(text: &str) -> Grid<u32> {
+        Grid::parse(&example_lines(text), |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn parsing() {
+        let grid = digits("123\n456");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.to_string(), "123\n456\n");
+
+        let err = Grid::parse(&example_lines("12\n3x"), |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(err.line(), Some(2));
+        let err = Grid::parse_from(&example_lines("12\n345"), 10, |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(err.line(), Some(11));
+        assert!(Grid::<u32>::from_rows(vec![vec![1], vec![2, 3]]).is_err());
+    }
+
+    #[test]
+    fn rejects_empty_rows() {
+        for text in &["\n", "\n12", "12\n\n34"] {
+            let err = Grid::parse_from(&example_lines(text), 5, |c| c.to_digit(10)).unwrap_err();
+            assert!(err.to_string().contains("empty row"), "{:?}: {}", text, err);
+        }
+        let err = Grid::parse(&example_lines("\n12"), |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(err.line(), Some(1));
+        let err = Grid::parse(&example_lines("12\n\n34"), |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(err.line(), Some(2));
+        assert!(Grid::<u32>::from_rows(Vec::new()).is_err());
+        assert!(Grid::<u32>::from_rows(vec![Vec::new(), Vec::new()]).is_err());
+    }
+
+    #[test]
+    fn wrapping() {
+        let grid = digits("123\n456");
+        assert_eq!(*grid.get_wrapping(4, 0), 2);
+        assert_eq!(*grid.get_wrapping(-1, -1), 6);
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = digits("123\n456\n789");
+        let values =
+            |pos: Vec<(usize, usize)>| -> Vec<u32> { pos.iter().map(|&x| grid[x]).collect() };
+        assert_eq!(values(grid.neighbours4((0, 0)).collect()), vec![2, 4]);
+        assert_eq!(
+            values(grid.neighbours8((1, 1)).collect()),
+            vec![1, 2, 3, 4, 6, 7, 8, 9]
+        );
+        assert_eq!(values(grid.neighbours8((2, 2)).collect()), vec![5, 6, 8]);
+        assert_eq!(values(grid.ray((0, 0), (1, 1)).collect()), vec![5, 9]);
+        assert_eq!(grid.ray((0, 1), (-1, 0)).count(), 0);
+    }
+
+    #[test]
+    fn views_and_transforms() {
+        let grid = digits("123\n456");
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<u32>>(), vec![3, 6]);
+        assert_eq!(Grid::from_fn(3, 2, |(x, y)| (x + 1 + 3 * y) as u32), grid);
+        assert_eq!(grid.rotate_right().to_string(), "41\n52\n63\n");
+        assert_eq!(grid.flip_vertical().to_string(), "456\n123\n");
+        assert_eq!(
+            grid.render(|&x| if x % 2 == 0 { '#' } else { '.' }),
+            ".#.\n#.#\n"
+        );
+    }
+}
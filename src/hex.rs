@@ -0,0 +1,383 @@
+use crate::error::{Error, Result};
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+use std::str::FromStr;
+
+// Hexes with pointy tops, so the neighbours are east and west plus four
+// diagonals. Coordinates are axial (q, r), with q growing east and r growing
+// south-east, like https://www.redblobgames.com/grids/hexagons/ describes.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Direction {
+    E,
+    SE,
+    SW,
+    W,
+    NW,
+    NE,
+}
+
+impl Direction {
+    // Clockwise, starting east
+    pub const ALL: [Direction; 6] = [
+        Direction::E,
+        Direction::SE,
+        Direction::SW,
+        Direction::W,
+        Direction::NW,
+        Direction::NE,
+    ];
+
+    pub fn offset(self) -> Hex {
+        match self {
+            Direction::E => Hex::new(1, 0),
+            Direction::SE => Hex::new(0, 1),
+            Direction::SW => Hex::new(-1, 1),
+            Direction::W => Hex::new(-1, 0),
+            Direction::NW => Hex::new(0, -1),
+            Direction::NE => Hex::new(1, -1),
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    // Turned clockwise by `steps` sixths of a circle, negative is counterclockwise
+    pub fn rotate(self, steps: i32) -> Direction {
+        Direction::ALL[(self.index() as i32 + steps).rem_euclid(6) as usize]
+    }
+
+    pub fn opposite(self) -> Direction {
+        self.rotate(3)
+    }
+}
+
+impl FromStr for Direction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "e" => Ok(Direction::E),
+            "se" => Ok(Direction::SE),
+            "sw" => Ok(Direction::SW),
+            "w" => Ok(Direction::W),
+            "nw" => Ok(Direction::NW),
+            "ne" => Ok(Direction::NE),
+            _ => Err(Error::parse(format!("unknown direction `{}`", s))),
+        }
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Direction::E => "e",
+            Direction::SE => "se",
+            Direction::SW => "sw",
+            Direction::W => "w",
+            Direction::NW => "nw",
+            Direction::NE => "ne",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+// Reads a path like `esenee` without separators. `n` and `s` always start a
+// two letter direction, so there's only one way to split it.
+pub fn parse_path(text: &str) -> Result<Vec<Direction>> {
+    let mut res = Vec::with_capacity(text.len());
+    let mut idx = 0;
+    while idx < text.len() {
+        let len = match text.as_bytes()[idx] {
+            b'n' | b's' => 2,
+            _ => 1,
+        };
+        let dir = text
+            .get(idx..idx + len)
+            .ok_or_else(|| Error::parse(format!("unknown direction `{}`", &text[idx..])))?;
+        res.push(dir.parse()?);
+        idx += len;
+    }
+    Ok(res)
+}
+
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Hex {
+    pub q: i32,
+    pub r: i32,
+}
+
+// The same hex as three coordinates that always add up to 0
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Cube {
+    pub q: i32,
+    pub r: i32,
+    pub s: i32,
+}
+
+impl Hex {
+    pub fn new(q: i32, r: i32) -> Self {
+        Hex { q, r }
+    }
+
+    pub fn origin() -> Self {
+        Hex::default()
+    }
+
+    // From "doubled width" coordinates, where east is (2, 0) and south-east
+    // is (1, 1). Column and row have to add up to an even number.
+    pub fn from_doubled(col: i32, row: i32) -> Option<Self> {
+        if (col + row) % 2 != 0 {
+            return None;
+        }
+        Some(Hex::new((col - row) / 2, row))
+    }
+
+    pub fn to_doubled(self) -> (i32, i32) {
+        (2 * self.q + self.r, self.r)
+    }
+
+    pub fn cube(self) -> Cube {
+        Cube {
+            q: self.q,
+            r: self.r,
+            s: -self.q - self.r,
+        }
+    }
+
+    pub fn neighbour(self, dir: Direction) -> Self {
+        self + dir.offset()
+    }
+
+    pub fn neighbours(self) -> impl Iterator<Item = Hex> {
+        Direction::ALL.iter().map(move |&dir| self.neighbour(dir))
+    }
+
+    // Where following every step of `path` from here ends
+    pub fn walk<'a, I>(self, path: I) -> Self
+    where
+        I: IntoIterator<Item = &'a Direction>,
+    {
+        path.into_iter().fold(self, |pos, &dir| pos.neighbour(dir))
+    }
+
+    // Number of steps away from the origin
+    pub fn length(self) -> u32 {
+        let cube = self.cube();
+        (cube.q.unsigned_abs() + cube.r.unsigned_abs() + cube.s.unsigned_abs()) / 2
+    }
+
+    pub fn distance(self, other: Self) -> u32 {
+        (self - other).length()
+    }
+
+    // A sixth of a turn clockwise around the origin, rotate `x - center`
+    // and add `center` back to turn around another hex
+    pub fn rotate_right(self) -> Self {
+        let cube = self.cube();
+        Hex::new(-cube.r, -cube.s)
+    }
+
+    pub fn rotate_left(self) -> Self {
+        let cube = self.cube();
+        Hex::new(-cube.s, -cube.q)
+    }
+
+    // Every hex `radius` steps away from `center`, clockwise from the one
+    // straight east. A ring of radius 0 is just the center.
+    pub fn ring(center: Hex, radius: u32) -> impl Iterator<Item = Hex> {
+        let radius = radius as i32;
+        let count = if radius == 0 { 1 } else { 6 * radius };
+        (0..count).map(move |idx| {
+            if radius == 0 {
+                return center;
+            }
+            let side = Direction::ALL[(idx / radius) as usize];
+            center + side.offset() * radius + side.rotate(2).offset() * (idx % radius)
+        })
+    }
+
+    // The center and then every ring around it up to `radius`
+    pub fn spiral(center: Hex, radius: u32) -> impl Iterator<Item = Hex> {
+        (0..=radius).flat_map(move |x| Hex::ring(center, x))
+    }
+
+    // The hexes a straight line from `self` to `other` passes through, both
+    // ends included
+    pub fn line(self, other: Hex) -> impl Iterator<Item = Hex> {
+        let steps = self.distance(other);
+        let (from, to) = (self.cube(), other.cube());
+        // Nudged a little so lines along hex edges always pick the same side
+        let lerp = move |a: i32, b: i32, t: f64, nudge: f64| a as f64 + nudge + (b - a) as f64 * t;
+        (0..=steps).map(move |idx| {
+            let t = if steps == 0 {
+                0.0
+            } else {
+                idx as f64 / steps as f64
+            };
+            Cube::round(
+                lerp(from.q, to.q, t, 1e-6),
+                lerp(from.r, to.r, t, 2e-6),
+                lerp(from.s, to.s, t, -3e-6),
+            )
+        })
+    }
+}
+
+impl Cube {
+    // The hex a fractional cube position falls in. The coordinate that
+    // rounded the most is fixed up so they still add up to 0.
+    pub fn round(q: f64, r: f64, s: f64) -> Hex {
+        let (mut rq, mut rr, rs) = (q.round(), r.round(), s.round());
+        let (dq, dr, ds) = ((rq - q).abs(), (rr - r).abs(), (rs - s).abs());
+        if dq > dr && dq > ds {
+            rq = -rr - rs;
+        } else if dr > ds {
+            rr = -rq - rs;
+        }
+        Hex::new(rq as i32, rr as i32)
+    }
+}
+
+impl From<Cube> for Hex {
+    fn from(cube: Cube) -> Hex {
+        Hex::new(cube.q, cube.r)
+    }
+}
+
+impl From<Hex> for Cube {
+    fn from(hex: Hex) -> Cube {
+        hex.cube()
+    }
+}
+
+impl Add for Hex {
+    type Output = Hex;
+
+    fn add(self, other: Hex) -> Hex {
+        Hex::new(self.q + other.q, self.r + other.r)
+    }
+}
+
+impl AddAssign for Hex {
+    fn add_assign(&mut self, other: Hex) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Hex {
+    type Output = Hex;
+
+    fn sub(self, other: Hex) -> Hex {
+        Hex::new(self.q - other.q, self.r - other.r)
+    }
+}
+
+impl Neg for Hex {
+    type Output = Hex;
+
+    fn neg(self) -> Hex {
+        Hex::new(-self.q, -self.r)
+    }
+}
+
+impl Mul<i32> for Hex {
+    type Output = Hex;
+
+    fn mul(self, factor: i32) -> Hex {
+        Hex::new(self.q * factor, self.r * factor)
+    }
+}
+
+impl fmt::Display for Hex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.q, self.r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn paths() {
+        let path = parse_path("nwwswee").unwrap();
+        assert_eq!(
+            path,
+            vec![
+                Direction::NW,
+                Direction::W,
+                Direction::SW,
+                Direction::E,
+                Direction::E
+            ]
+        );
+        assert_eq!(Hex::origin().walk(&path), Hex::origin());
+        assert_eq!(
+            Hex::origin().walk(&parse_path("esew").unwrap()),
+            Hex::new(0, 1)
+        );
+        assert!(parse_path("esn").is_err());
+        assert!(parse_path("ens").is_err());
+        assert!(parse_path("ex").is_err());
+        let text: String = path.iter().map(Direction::to_string).collect();
+        assert_eq!(text, "nwwswee");
+    }
+
+    #[test]
+    fn coordinates() {
+        let hex = Hex::new(2, -3);
+        assert_eq!(hex.cube(), Cube { q: 2, r: -3, s: 1 });
+        assert_eq!(Hex::from(hex.cube()), hex);
+        assert_eq!(hex.to_doubled(), (1, -3));
+        assert_eq!(Hex::from_doubled(1, -3), Some(hex));
+        assert_eq!(Hex::from_doubled(1, 0), None);
+        assert_eq!(Hex::from_doubled(2, 0), Some(Direction::E.offset()));
+        assert_eq!(hex.length(), 3);
+        assert_eq!(hex.distance(Hex::new(-1, 0)), 3);
+    }
+
+    #[test]
+    fn directions_and_rotation() {
+        assert_eq!(Direction::E.rotate(1), Direction::SE);
+        assert_eq!(Direction::E.rotate(-1), Direction::NE);
+        assert_eq!(Direction::NW.opposite(), Direction::SE);
+        for dir in Direction::ALL.iter() {
+            assert_eq!(dir.offset().rotate_right(), dir.rotate(1).offset());
+            assert_eq!(dir.offset().rotate_left(), dir.rotate(-1).offset());
+            assert_eq!(dir.offset() + dir.opposite().offset(), Hex::origin());
+        }
+        let hex = Hex::new(3, -1);
+        let turned = (0..6).fold(hex, |x, _| x.rotate_right());
+        assert_eq!(turned, hex);
+    }
+
+    #[test]
+    fn rings_and_spirals() {
+        let center = Hex::new(1, 2);
+        assert_eq!(Hex::ring(center, 0).collect::<Vec<Hex>>(), vec![center]);
+        for radius in 1..5 {
+            let ring: Vec<Hex> = Hex::ring(center, radius).collect();
+            assert_eq!(ring.len(), 6 * radius as usize);
+            assert!(ring.iter().all(|x| x.distance(center) == radius));
+            // Every hex is next to the one before it
+            assert!(ring
+                .iter()
+                .zip(ring.iter().cycle().skip(1))
+                .all(|(a, b)| a.distance(*b) == 1));
+        }
+        let spiral: HashSet<Hex> = Hex::spiral(center, 3).collect();
+        assert_eq!(spiral.len(), 37);
+    }
+
+    #[test]
+    fn lines() {
+        let (from, to) = (Hex::new(-2, 0), Hex::new(3, -2));
+        let line: Vec<Hex> = from.line(to).collect();
+        assert_eq!(line.len(), 6);
+        assert_eq!((line[0], line[5]), (from, to));
+        assert!(line.windows(2).all(|x| x[0].distance(x[1]) == 1));
+        assert_eq!(from.line(from).collect::<Vec<Hex>>(), vec![from]);
+    }
+}
@@ -0,0 +1,193 @@
+use crate::error::{Error, Result};
+use crate::grid::Grid;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+// Red, green and blue, 0 to 255 each
+pub type Rgb = [u8; 3];
+
+// One picture of a map, black and white or in colour
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Frame {
+    Bitmap(Grid<bool>),
+    Pixmap(Grid<Rgb>),
+}
+
+impl Frame {
+    fn extension(&self) -> &'static str {
+        match self {
+            Frame::Bitmap(_) => "pbm",
+            Frame::Pixmap(_) => "ppm",
+        }
+    }
+
+    pub fn write<W: Write>(&self, out: W, scale: usize) -> io::Result<()> {
+        match self {
+            Frame::Bitmap(grid) => write_pbm(&upscale(grid, scale), out),
+            Frame::Pixmap(grid) => write_ppm(&upscale(grid, scale), out),
+        }
+    }
+
+    pub fn save(&self, path: &Path, scale: usize) -> Result<()> {
+        let file = File::create(path).map_err(|e| Error::io(path, e))?;
+        let mut out = BufWriter::new(file);
+        self.write(&mut out, scale)
+            .and_then(|_| out.flush())
+            .map_err(|e| Error::io(path, e))
+    }
+}
+
+// Every cell becomes a `factor` by `factor` square
+pub fn upscale<T: Clone>(grid: &Grid<T>, factor: usize) -> Grid<T> {
+    Grid::from_fn(grid.width() * factor, grid.height() * factor, |(x, y)| {
+        grid[(x / factor, y / factor)].clone()
+    })
+}
+
+// Binary PBM, true is black. Every row is padded to whole bytes.
+pub fn write_pbm<W: Write>(grid: &Grid<bool>, mut out: W) -> io::Result<()> {
+    write!(out, "P4\n{} {}\n", grid.width(), grid.height())?;
+    for row in grid.rows() {
+        let bytes: Vec<u8> = row
+            .chunks(8)
+            .map(|bits| {
+                bits.iter()
+                    .enumerate()
+                    .filter(|(_, &x)| x)
+                    .fold(0, |byte, (idx, _)| byte | 0x80 >> idx)
+            })
+            .collect();
+        out.write_all(&bytes)?;
+    }
+    Ok(())
+}
+
+// Binary PPM with 8 bits per channel
+pub fn write_ppm<W: Write>(grid: &Grid<Rgb>, mut out: W) -> io::Result<()> {
+    write!(out, "P6\n{} {}\n255\n", grid.width(), grid.height())?;
+    let bytes: Vec<u8> = grid.iter().flatten().copied().collect();
+    out.write_all(&bytes)
+}
+
+// Collects the frames of a run. Either every frame is saved, numbered, in a
+// directory of its own, or only the last one is kept and saved by `finish`.
+pub struct Recorder {
+    dir: PathBuf,
+    name: String,
+    scale: usize,
+    all_frames: bool,
+    count: usize,
+    last: Option<Frame>,
+    written: Vec<PathBuf>,
+}
+
+impl Recorder {
+    pub fn new<P: Into<PathBuf>>(dir: P, name: &str, scale: usize, all_frames: bool) -> Self {
+        Recorder {
+            dir: dir.into(),
+            name: name.to_string(),
+            scale: scale.max(1),
+            all_frames,
+            count: 0,
+            last: None,
+            written: Vec::new(),
+        }
+    }
+
+    fn create_dir(dir: &Path) -> Result<()> {
+        fs::create_dir_all(dir).map_err(|e| Error::io(dir, e))
+    }
+
+    pub fn frame(&mut self, frame: Frame) -> Result<()> {
+        if self.all_frames {
+            let dir = self.dir.join(&self.name);
+            if self.count == 0 {
+                Recorder::create_dir(&dir)?;
+            }
+            let path = dir.join(format!("{:04}.{}", self.count, frame.extension()));
+            frame.save(&path, self.scale)?;
+            self.written.push(path);
+        } else {
+            self.last = Some(frame);
+        }
+        self.count += 1;
+        Ok(())
+    }
+
+    // Saves the last frame when only that one is kept, and returns every file written
+    pub fn finish(mut self) -> Result<Vec<PathBuf>> {
+        if let Some(frame) = self.last.take() {
+            Recorder::create_dir(&self.dir)?;
+            let path = self
+                .dir
+                .join(format!("{}.{}", self.name, frame.extension()));
+            frame.save(&path, self.scale)?;
+            self.written.push(path);
+        }
+        Ok(self.written)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::example_lines;
+    use std::env;
+    use std::process;
+
+    fn bitmap(text: &str) -> Grid<bool> {
+        Grid::parse(&example_lines(text), |c| Some(c == '#')).unwrap()
+    }
+
+    #[test]
+    fn writes_pbm() {
+        let mut out = Vec::new();
+        write_pbm(&bitmap("#........#\n.#.#......"), &mut out).unwrap();
+        assert_eq!(out, b"P4\n10 2\n\x80\x40\x50\x00");
+    }
+
+    #[test]
+    fn writes_ppm() {
+        let grid = Grid::from_rows(vec![vec![[255, 0, 0], [0, 0, 7]]]).unwrap();
+        let mut out = Vec::new();
+        write_ppm(&grid, &mut out).unwrap();
+        assert_eq!(out, b"P6\n2 1\n255\n\xff\x00\x00\x00\x00\x07");
+
+        let mut out = Vec::new();
+        Frame::Pixmap(grid).write(&mut out, 2).unwrap();
+        assert_eq!(out.len(), "P6\n4 2\n255\n".len() + 4 * 2 * 3);
+    }
+
+    #[test]
+    fn upscales() {
+        let grid = upscale(&bitmap("#.\n.#"), 2);
+        assert_eq!(
+            grid.render(|&x| if x { '#' } else { '.' }),
+            "##..\n##..\n..##\n..##\n"
+        );
+    }
+
+    #[test]
+    fn records_frames() {
+        let dir = env::temp_dir().join(format!("rustaocean-image-{}", process::id()));
+        let frames = [bitmap("#."), bitmap(".#")];
+
+        let mut all = Recorder::new(&dir, "all", 1, true);
+        let mut last = Recorder::new(&dir, "last", 1, false);
+        for frame in frames.iter() {
+            all.frame(Frame::Bitmap(frame.clone())).unwrap();
+            last.frame(Frame::Bitmap(frame.clone())).unwrap();
+        }
+        let all = all.finish().unwrap();
+        let last = last.finish().unwrap();
+        assert_eq!(
+            all,
+            vec![dir.join("all/0000.pbm"), dir.join("all/0001.pbm")]
+        );
+        assert_eq!(last, vec![dir.join("last.pbm")]);
+        assert_eq!(fs::read(&last[0]).unwrap(), b"P4\n2 1\n\x40");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
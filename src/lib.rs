@@ -1,4 +1,14 @@
+pub mod alloc;
+pub mod automaton;
 #[macro_use]
 pub mod common;
-pub mod days;
+pub mod console;
+#[macro_use]
+pub mod registry;
+pub mod error;
+pub mod grid;
+pub mod hex;
+pub mod image;
 pub mod points;
+pub mod solver;
+pub mod y2020;
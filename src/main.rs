@@ -1,21 +1,267 @@
-mod common;
-mod days;
-mod points;
+mod answers;
+mod bench;
+mod cli;
+mod debug;
+mod render;
+mod report;
+mod runner;
+mod scaffold;
+mod watch;
 
-fn main() {
-    for day in days::all_numbers() {
-        if let Some(solver) = days::get_solver(day) {
-            let input = common::get_day_input(day).expect(&format!(
-                "Problem occured while getting input for day{:02}",
-                day
-            ));
-
-            let (solution, time) = solver(&input);
-            println!(
-                "Solution for day{:02}: ({}, {}), took {:?}",
-                day, solution.0, solution.1, time
-            );
+use answers::{Answers, Verdict};
+use cli::{Args, BenchArgs, Command, InputSource};
+use report::{Record, Reporter, Totals};
+use rustaocean2020::common::{self, Part};
+use rustaocean2020::error::{Error, Result};
+use rustaocean2020::registry;
+use rustaocean2020::solver::DaySolver;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::PathBuf;
+use std::process;
+use std::time::{Duration, Instant};
+
+// Name of the puzzle input among the named ones in batch mode
+const DEFAULT_INPUT: &str = "default";
+
+// One day run over one of its inputs
+struct Task {
+    day: u8,
+    // Only set in batch mode, where a day runs over several inputs
+    name: Option<String>,
+    input: InputSource,
+    answers: Option<PathBuf>,
+}
+
+fn tasks(args: &Args) -> Result<Vec<Task>> {
+    let mut tasks = Vec::new();
+    for &day in &args.days {
+        let task = |name: Option<&str>, input, answers| Task {
+            day,
+            name: name.map(str::to_string),
+            input,
+            answers,
+        };
+        match &args.input {
+            InputSource::DayFile => tasks.push(task(
+                None,
+                InputSource::DayFile,
+                Some(answers::default_path(args.year)),
+            )),
+            InputSource::File(path) => {
+                tasks.push(task(None, InputSource::File(path.clone()), None))
+            }
+            InputSource::Stdin => tasks.push(task(None, InputSource::Stdin, None)),
+            InputSource::Batch => {
+                tasks.push(task(
+                    Some(DEFAULT_INPUT),
+                    InputSource::DayFile,
+                    Some(answers::default_path(args.year)),
+                ));
+                let dir = common::named_inputs_dir(args.year, day);
+                for (name, path) in common::list_named_inputs(&dir)? {
+                    let answers = answers::input_path(&path);
+                    tasks.push(task(Some(&name), InputSource::File(path), Some(answers)));
+                }
+            }
+        }
+    }
+    Ok(tasks)
+}
+
+fn load_input(year: u16, day: u8, source: &InputSource) -> Result<Vec<String>> {
+    match source {
+        InputSource::DayFile | InputSource::Batch => common::get_day_input(year, day),
+        InputSource::File(path) => common::get_input(path),
+        InputSource::Stdin => common::get_stdin_input(),
+    }
+}
+
+// `solve` runs on the worker threads, `report` gets its results back in task
+// order. A broken day or input shouldn't stop the others from running, so
+// errors are only reported and the caller learns whether any task failed.
+fn for_each_task<T, S, R>(args: &Args, tasks: &[Task], solve: S, mut report: R) -> bool
+where
+    T: Send,
+    S: Fn(&Task, &dyn DaySolver, &[String]) -> Result<T> + Sync,
+    R: FnMut(&Task, T),
+{
+    let mut failed = false;
+    runner::run_ordered(
+        tasks,
+        args.jobs,
+        |task| {
+            let solver = registry::get_solver(args.year, task.day)
+                .ok_or_else(|| Error::solve("no solver for this day"))?;
+            load_input(args.year, task.day, &task.input)
+                .and_then(|input| solve(task, solver, &input))
+        },
+        |task, result| match result.unwrap_or_else(runner::panicked) {
+            Ok(res) => report(task, res),
+            Err(err) => {
+                match &task.name {
+                    Some(name) => eprintln!("error: {} (input {})", err.for_day(task.day), name),
+                    None => eprintln!("error: {}", err.for_day(task.day)),
+                }
+                failed = true;
+            }
+        },
+    );
+    failed
+}
+
+fn run(args: &Args) -> bool {
+    let tasks = match tasks(args) {
+        Ok(tasks) => tasks,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return true;
+        }
+    };
+
+    // Answers only make sense for the inputs they were recorded from, each
+    // file is loaded once however many days share it
+    let mut answers = BTreeMap::new();
+    for path in tasks.iter().filter_map(|x| x.answers.as_ref()) {
+        if answers.contains_key(path) {
+            continue;
+        }
+        match Answers::load(path) {
+            Ok(loaded) => answers.insert(path.clone(), loaded),
+            Err(err) => {
+                eprintln!("error: {}", err);
+                return true;
+            }
+        };
+    }
+
+    let mut mismatch = false;
+    let mut recorded = BTreeSet::new();
+    let started = Instant::now();
+    let mut day_sum = Duration::default();
+    let mut reporter = Reporter::new(args.format);
+    let solve = |_: &Task, solver: &dyn DaySolver, input: &[String]| solver.run(input, args.part);
+    let failed = for_each_task(args, &tasks, solve, |task, solution| {
+        day_sum += solution.timings.total();
+
+        let mut verdicts = [None, None];
+        if let Some(path) = &task.answers {
+            let answers = answers
+                .get_mut(path)
+                .expect("answers are loaded before the run");
+            let parts = [(Part::One, &solution.part1), (Part::Two, &solution.part2)];
+            for (verdict, (part, answer)) in verdicts.iter_mut().zip(parts) {
+                if let Some(answer) = answer {
+                    let answer = answer.to_string();
+                    let check = answers.check(task.day, part, &answer);
+                    match check {
+                        Verdict::Fail(_) => mismatch = true,
+                        Verdict::New if args.record && answers.record(task.day, part, &answer) => {
+                            recorded.insert(path.clone());
+                        }
+                        _ => {}
+                    }
+                    *verdict = Some(check);
+                }
+            }
+        }
+
+        reporter.record(&Record {
+            year: args.year,
+            day: task.day,
+            input: task.name.as_deref(),
+            solution: &solution,
+            verdicts,
+        });
+    });
+    reporter.finish(&Totals {
+        wall: started.elapsed(),
+        day_sum,
+        jobs: args.jobs,
+    });
+
+    for path in &recorded {
+        if let Err(err) = answers[path].save() {
+            eprintln!("error: {}", err);
+            return true;
         }
     }
+    failed || mismatch
+}
+
+fn run_bench(args: &Args, bench_args: &BenchArgs) -> bool {
+    let tasks = match tasks(args) {
+        Ok(tasks) => tasks,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return true;
+        }
+    };
+
+    let solve = |task: &Task, solver: &dyn DaySolver, input: &[String]| {
+        bench::bench(
+            task.day,
+            task.name.as_deref(),
+            solver,
+            input,
+            args.part,
+            bench_args.warmup,
+            bench_args.iterations,
+        )
+    };
+    let failed = for_each_task(args, &tasks, solve, |_, report| println!("{}", report));
     println!("DONE");
+    failed
+}
+
+fn main() {
+    let failed = match cli::parse(std::env::args().skip(1)) {
+        Ok(Command::Run(args)) => run(&args),
+        Ok(Command::Bench(args, bench_args)) => run_bench(&args, &bench_args),
+        Ok(Command::Watch(args, watch_args)) => watch::watch(&args, &watch_args),
+        Ok(Command::Render(args, render_args)) => match render::render(&args, &render_args) {
+            Ok(written) => {
+                println!(
+                    "wrote {} image(s) to {}",
+                    written.len(),
+                    render_args.out.display()
+                );
+                false
+            }
+            Err(err) => {
+                eprintln!("error: {}", err.for_day(args.days[0]));
+                true
+            }
+        },
+        Ok(Command::Debug(args)) => match debug::debug(&args) {
+            Ok(()) => false,
+            Err(err) => {
+                eprintln!("error: {}", err.for_day(args.days[0]));
+                true
+            }
+        },
+        Ok(Command::New(year, day)) => match scaffold::new_day(year, day) {
+            Ok(written) => {
+                for path in written {
+                    println!("wrote {}", path.display());
+                }
+                false
+            }
+            Err(err) => {
+                eprintln!("error: {}", err);
+                true
+            }
+        },
+        Ok(Command::Help) => {
+            println!("{}", cli::USAGE);
+            return;
+        }
+        Err(err) => {
+            eprintln!("error: {}\nRun with `--help` for usage.", err);
+            process::exit(2);
+        }
+    };
+
+    if failed {
+        process::exit(1);
+    }
 }
@@ -1,63 +1,281 @@
-use itertools::iproduct;
+use std::fmt;
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign};
 
-pub trait Point: Sized {
-    fn get_neighbours(&self) -> Vec<Self>;
-    fn new(from: (i32, i32, i32, i32)) -> Self;
+// Point on an N dimensional integer lattice
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PointN<const N: usize>(pub [i32; N]);
+
+pub type Point2 = PointN<2>;
+pub type Point3 = PointN<3>;
+pub type Point4 = PointN<4>;
+
+impl<const N: usize> PointN<N> {
+    pub fn new(coords: [i32; N]) -> Self {
+        PointN(coords)
+    }
+
+    pub fn origin() -> Self {
+        PointN([0; N])
+    }
+
+    // The same point in more dimensions, the extra coordinates are 0
+    pub fn embed<const M: usize>(from: PointN<M>) -> Self {
+        assert!(M <= N, "can't embed {} dimensions in {}", M, N);
+        let mut coords = [0; N];
+        coords[..M].copy_from_slice(&from.0);
+        PointN(coords)
+    }
+
+    pub fn coords(&self) -> &[i32; N] {
+        &self.0
+    }
+
+    pub fn manhattan(&self, other: &Self) -> u32 {
+        self.0
+            .iter()
+            .zip(other.0.iter())
+            .map(|(a, b)| (a - b).unsigned_abs())
+            .sum()
+    }
+
+    pub fn chebyshev(&self, other: &Self) -> u32 {
+        self.0
+            .iter()
+            .zip(other.0.iter())
+            .map(|(a, b)| (a - b).unsigned_abs())
+            .max()
+            .unwrap_or(0)
+    }
+
+    // All 3^N - 1 points with a Chebyshev distance of 1
+    pub fn neighbours(&self) -> Neighbours<N> {
+        Neighbours {
+            center: *self,
+            next: 0,
+        }
+    }
+}
+
+impl<const N: usize> Index<usize> for PointN<N> {
+    type Output = i32;
+
+    fn index(&self, axis: usize) -> &i32 {
+        &self.0[axis]
+    }
+}
+
+impl<const N: usize> IndexMut<usize> for PointN<N> {
+    fn index_mut(&mut self, axis: usize) -> &mut i32 {
+        &mut self.0[axis]
+    }
+}
+
+impl<const N: usize> Add for PointN<N> {
+    type Output = Self;
+
+    fn add(mut self, other: Self) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl<const N: usize> AddAssign for PointN<N> {
+    fn add_assign(&mut self, other: Self) {
+        self.0
+            .iter_mut()
+            .zip(other.0.iter())
+            .for_each(|(a, b)| *a += b);
+    }
+}
+
+impl<const N: usize> Sub for PointN<N> {
+    type Output = Self;
+
+    fn sub(mut self, other: Self) -> Self {
+        self -= other;
+        self
+    }
+}
+
+impl<const N: usize> SubAssign for PointN<N> {
+    fn sub_assign(&mut self, other: Self) {
+        self.0
+            .iter_mut()
+            .zip(other.0.iter())
+            .for_each(|(a, b)| *a -= b);
+    }
 }
 
-#[derive(Copy, Clone, Hash, PartialEq, Eq)]
-pub struct Point3 {
-    x: i32,
-    y: i32,
-    z: i32,
+impl<const N: usize> Neg for PointN<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        PointN(self.0.map(|x| -x))
+    }
+}
+
+impl<const N: usize> Mul<i32> for PointN<N> {
+    type Output = Self;
+
+    fn mul(self, factor: i32) -> Self {
+        PointN(self.0.map(|x| x * factor))
+    }
 }
 
-impl Point for Point3 {
-    fn new(from: (i32, i32, i32, i32)) -> Self {
-        Self {
-            x: from.0,
-            y: from.1,
-            z: from.2,
+impl<const N: usize> fmt::Display for PointN<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "(")?;
+        for (idx, x) in self.0.iter().enumerate() {
+            if idx > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", x)?;
         }
+        write!(f, ")")
     }
+}
 
-    fn get_neighbours(&self) -> Vec<Self> {
-        let xs = (self.x - 1)..=(self.x + 1);
-        let ys = (self.y - 1)..=(self.y + 1);
-        let zs = (self.z - 1)..=(self.z + 1);
-        iproduct!(xs, ys, zs)
-            .map(|(x, y, z)| Self::new((x, y, z, 0)))
-            .filter(|p| self != p)
-            .collect()
+// Counts through the 3^N offsets in base 3, each digit is -1, 0 or +1 on one
+// axis. The all zero offset in the middle is the point itself and skipped.
+pub struct Neighbours<const N: usize> {
+    center: PointN<N>,
+    next: usize,
+}
+
+impl<const N: usize> Neighbours<N> {
+    const COUNT: usize = 3usize.pow(N as u32);
+    const CENTER: usize = (Self::COUNT - 1) / 2;
+}
+
+impl<const N: usize> Iterator for Neighbours<N> {
+    type Item = PointN<N>;
+
+    fn next(&mut self) -> Option<PointN<N>> {
+        if self.next == Self::CENTER {
+            self.next += 1;
+        }
+        if self.next >= Self::COUNT {
+            return None;
+        }
+
+        let mut res = self.center;
+        let mut digits = self.next;
+        for x in res.0.iter_mut() {
+            *x += (digits % 3) as i32 - 1;
+            digits /= 3;
+        }
+        self.next += 1;
+        Some(res)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let left = Self::COUNT - self.next - usize::from(self.next <= Self::CENTER);
+        (left, Some(left))
     }
 }
 
-#[derive(Copy, Clone, Hash, PartialEq, Eq)]
-pub struct Point4 {
-    x: i32,
-    y: i32,
-    z: i32,
-    w: i32,
+impl<const N: usize> ExactSizeIterator for Neighbours<N> {}
+
+// Smallest box holding a set of points, both corners are inside it
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Bounds<const N: usize> {
+    pub min: PointN<N>,
+    pub max: PointN<N>,
 }
 
-impl Point for Point4 {
-    fn new(from: (i32, i32, i32, i32)) -> Self {
-        Self {
-            x: from.0,
-            y: from.1,
-            z: from.2,
-            w: from.3,
+impl<const N: usize> Bounds<N> {
+    pub fn new(point: PointN<N>) -> Self {
+        Bounds {
+            min: point,
+            max: point,
         }
     }
 
-    fn get_neighbours(&self) -> Vec<Self> {
-        let xs = (self.x - 1)..=(self.x + 1);
-        let ys = (self.y - 1)..=(self.y + 1);
-        let zs = (self.z - 1)..=(self.z + 1);
-        let ws = (self.w - 1)..=(self.w + 1);
-        iproduct!(xs, ys, zs, ws)
-            .map(Self::new)
-            .filter(|p| self != p)
-            .collect()
+    // None when there are no points to hold
+    pub fn of<'a, I>(points: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = &'a PointN<N>>,
+    {
+        let mut points = points.into_iter();
+        let mut res = Bounds::new(*points.next()?);
+        points.for_each(|x| res.include(x));
+        Some(res)
+    }
+
+    pub fn include(&mut self, point: &PointN<N>) {
+        for axis in 0..N {
+            self.min[axis] = self.min[axis].min(point[axis]);
+            self.max[axis] = self.max[axis].max(point[axis]);
+        }
+    }
+
+    pub fn contains(&self, point: &PointN<N>) -> bool {
+        (0..N).all(|axis| (self.min[axis]..=self.max[axis]).contains(&point[axis]))
+    }
+
+    // Length along every axis
+    pub fn size(&self) -> PointN<N> {
+        PointN(std::array::from_fn(|axis| {
+            self.max[axis] - self.min[axis] + 1
+        }))
+    }
+
+    pub fn volume(&self) -> u64 {
+        self.size().0.iter().map(|&x| x as u64).product()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn arithmetic_and_distances() {
+        let a = Point3::new([1, -2, 3]);
+        let b = Point3::new([4, 2, 3]);
+        assert_eq!(a + b, Point3::new([5, 0, 6]));
+        assert_eq!(b - a, Point3::new([3, 4, 0]));
+        assert_eq!(-a * 2, Point3::new([-2, 4, -6]));
+        assert_eq!(a.manhattan(&b), 7);
+        assert_eq!(a.chebyshev(&b), 4);
+        assert_eq!(
+            Point4::embed(Point2::new([7, 8])),
+            Point4::new([7, 8, 0, 0])
+        );
+        assert_eq!(a.to_string(), "(1, -2, 3)");
+    }
+
+    #[test]
+    fn neighbours() {
+        let center = Point3::new([5, 5, 5]);
+        let neighs: HashSet<Point3> = center.neighbours().collect();
+        assert_eq!(center.neighbours().len(), 26);
+        assert_eq!(neighs.len(), 26);
+        assert!(!neighs.contains(&center));
+        assert!(neighs.iter().all(|x| x.chebyshev(&center) == 1));
+
+        let mut iter = PointN::new([0; 6]).neighbours();
+        assert_eq!(iter.len(), 728);
+        iter.nth(400);
+        assert_eq!(iter.len(), 327);
+        assert_eq!(iter.count(), 327);
+    }
+
+    #[test]
+    fn bounds() {
+        let points = [
+            Point2::new([1, 5]),
+            Point2::new([-2, 3]),
+            Point2::new([0, 7]),
+        ];
+        let bounds = Bounds::of(&points).unwrap();
+        assert_eq!(bounds.min, Point2::new([-2, 3]));
+        assert_eq!(bounds.max, Point2::new([1, 7]));
+        assert_eq!(bounds.size(), Point2::new([4, 5]));
+        assert_eq!(bounds.volume(), 20);
+        assert!(bounds.contains(&Point2::new([0, 4])));
+        assert!(!bounds.contains(&Point2::new([2, 4])));
+        assert_eq!(Bounds::<2>::of(&[]), None);
     }
 }
@@ -0,0 +1,43 @@
+use crate::solver::DaySolver;
+
+// Declares the day modules of one year and collects their solvers. Every
+// solver carries its own year and day number, so this list is the only
+// place a new day is added.
+macro_rules! days {
+    ($($module:ident::$solver:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        pub static SOLVERS: &[&dyn $crate::solver::DaySolver] = &[$(&$module::$solver),*];
+    };
+}
+
+// One entry per year module, `new` adds them here
+static YEARS: &[&[&dyn DaySolver]] = &[crate::y2020::SOLVERS];
+
+fn all_solvers() -> impl Iterator<Item = &'static dyn DaySolver> {
+    YEARS.iter().flat_map(|x| x.iter().copied())
+}
+
+pub fn all_years() -> Vec<u16> {
+    let mut years: Vec<u16> = all_solvers().map(|x| x.year()).collect();
+    years.sort_unstable();
+    years.dedup();
+    years
+}
+
+pub fn latest_year() -> Option<u16> {
+    all_years().last().copied()
+}
+
+pub fn all_days(year: u16) -> Vec<u8> {
+    let mut days: Vec<u8> = all_solvers()
+        .filter(|x| x.year() == year)
+        .map(|x| x.day())
+        .collect();
+    days.sort_unstable();
+    days
+}
+
+pub fn get_solver(year: u16, day: u8) -> Option<&'static dyn DaySolver> {
+    all_solvers().find(|x| x.year() == year && x.day() == day)
+}
@@ -0,0 +1,51 @@
+use crate::cli::{Args, RenderArgs};
+use rustaocean2020::common::Part;
+use rustaocean2020::error::{Error, Result};
+use rustaocean2020::image::Recorder;
+use rustaocean2020::solver::Solver;
+use rustaocean2020::y2020::{day11, day17, day20, day24};
+use std::path::PathBuf;
+
+// Days that can draw their state
+const RENDERABLE: &[(u16, u8)] = &[(2020, 11), (2020, 17), (2020, 20), (2020, 24)];
+
+// Renders one day and returns the files written
+pub fn render(args: &Args, render_args: &RenderArgs) -> Result<Vec<PathBuf>> {
+    let day = args.days[0];
+    if !RENDERABLE.contains(&(args.year, day)) {
+        let days: Vec<String> = RENDERABLE
+            .iter()
+            .map(|(year, day)| format!("{}/{}", year, day))
+            .collect();
+        return Err(Error::solve(format!(
+            "no images for this day, only for {}",
+            days.join(", ")
+        )));
+    }
+    let part = args.part.unwrap_or(Part::One);
+    let name = format!(
+        "{}_day{:02}_part{}",
+        args.year,
+        day,
+        match part {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    );
+    let mut recorder = Recorder::new(
+        &render_args.out,
+        &name,
+        render_args.scale,
+        render_args.frames,
+    );
+
+    let input = crate::load_input(args.year, day, &args.input)?;
+    match day {
+        11 => day11::render(&day11::Day11.parse(&input)?, part, &mut recorder)?,
+        17 => day17::render(&day17::Day17.parse(&input)?, part, &mut recorder)?,
+        20 => day20::render(&day20::Day20.parse(&input)?, &mut recorder)?,
+        24 => day24::render(&day24::Day24.parse(&input)?, part, &mut recorder)?,
+        _ => unreachable!("day {} is listed as renderable", day),
+    }
+    recorder.finish()
+}
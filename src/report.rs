@@ -0,0 +1,405 @@
+use crate::answers::Verdict;
+use rustaocean2020::alloc::AllocStats;
+use rustaocean2020::common::Answer;
+use rustaocean2020::solver::Solution;
+use std::str::FromStr;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+    Markdown,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            "markdown" | "md" => Ok(Format::Markdown),
+            _ => Err(format!(
+                "invalid format `{}`, expected text, json, csv or markdown",
+                s
+            )),
+        }
+    }
+}
+
+// Allocation columns stay empty unless built with the `alloc-stats` feature
+static COLUMNS: [&str; 19] = [
+    "year",
+    "day",
+    "input",
+    "part1",
+    "part2",
+    "parse_ns",
+    "part1_ns",
+    "part2_ns",
+    "parse_alloc_bytes",
+    "parse_allocs",
+    "parse_peak_bytes",
+    "part1_alloc_bytes",
+    "part1_allocs",
+    "part1_peak_bytes",
+    "part2_alloc_bytes",
+    "part2_allocs",
+    "part2_peak_bytes",
+    "part1_status",
+    "part2_status",
+];
+
+fn is_number(column: &str) -> bool {
+    !matches!(
+        column,
+        "input" | "part1" | "part2" | "part1_status" | "part2_status"
+    )
+}
+
+// One solved day. The input is only named in batch mode. Verdicts are None for
+// parts that weren't run or when no answers are known for the input.
+pub struct Record<'a> {
+    pub year: u16,
+    pub day: u8,
+    pub input: Option<&'a str>,
+    pub solution: &'a Solution,
+    pub verdicts: [Option<Verdict>; 2],
+}
+
+fn nanos(time: Option<Duration>) -> Option<String> {
+    time.map(|x| x.as_nanos().to_string())
+}
+
+fn alloc_fields(stats: Option<AllocStats>) -> [Option<String>; 3] {
+    match stats {
+        Some(x) => [
+            Some(x.bytes.to_string()),
+            Some(x.count.to_string()),
+            Some(x.peak.to_string()),
+        ],
+        None => [None, None, None],
+    }
+}
+
+fn human_bytes(bytes: u64) -> String {
+    let units = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < units.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    match unit {
+        0 => format!("{} B", bytes),
+        _ => format!("{:.1} {}", value, units[unit]),
+    }
+}
+
+fn describe_allocs(name: &str, stats: &AllocStats) -> String {
+    format!(
+        "{} {} in {} allocs (peak {})",
+        name,
+        human_bytes(stats.bytes),
+        stats.count,
+        human_bytes(stats.peak)
+    )
+}
+
+impl Record<'_> {
+    // Every column as text, in the order of `COLUMNS`, None for missing values
+    fn fields(&self) -> Vec<Option<String>> {
+        let timings = &self.solution.timings;
+        let status = |idx: usize| self.verdicts[idx].as_ref().map(|x| x.name().to_string());
+        let allocs = self.solution.allocations;
+        let mut fields = vec![
+            Some(self.year.to_string()),
+            Some(self.day.to_string()),
+            self.input.map(str::to_string),
+            self.solution.part1.as_ref().map(Answer::to_string),
+            self.solution.part2.as_ref().map(Answer::to_string),
+            nanos(Some(timings.parse)),
+            nanos(timings.part1),
+            nanos(timings.part2),
+        ];
+        fields.extend(alloc_fields(allocs.map(|x| x.parse)));
+        fields.extend(alloc_fields(allocs.and_then(|x| x.part1)));
+        fields.extend(alloc_fields(allocs.and_then(|x| x.part2)));
+        fields.extend([status(0), status(1)]);
+        fields
+    }
+
+    fn describe_memory(&self) -> Option<String> {
+        let allocs = self.solution.allocations?;
+        let mut phases = vec![describe_allocs("parse", &allocs.parse)];
+        if let Some(stats) = &allocs.part1 {
+            phases.push(describe_allocs("part1", stats));
+        }
+        if let Some(stats) = &allocs.part2 {
+            phases.push(describe_allocs("part2", stats));
+        }
+        Some(phases.join(", "))
+    }
+
+    fn describe(&self) -> String {
+        let timings = &self.solution.timings;
+        let mut phases = vec![format!("parse {:?}", timings.parse)];
+        let day = match self.input {
+            Some(name) => format!("day{:02} [{}]", self.day, name),
+            None => format!("day{:02}", self.day),
+        };
+        let answers = match (&self.solution.part1, &self.solution.part2) {
+            (Some(p1), Some(p2)) => format!("{}: ({}, {})", day, p1, p2),
+            (Some(p1), None) => format!("{} part 1: {}", day, p1),
+            (None, Some(p2)) => format!("{} part 2: {}", day, p2),
+            (None, None) => day,
+        };
+        if let Some(time) = timings.part1 {
+            phases.push(format!("part1 {:?}", time));
+        }
+        if let Some(time) = timings.part2 {
+            phases.push(format!("part2 {:?}", time));
+        }
+
+        format!(
+            "Solution for {}, took {:?} ({})",
+            answers,
+            timings.total(),
+            phases.join(", ")
+        )
+    }
+}
+
+fn json_string(txt: &str) -> String {
+    let mut res = String::from("\"");
+    for c in txt.chars() {
+        match c {
+            '"' => res.push_str("\\\""),
+            '\\' => res.push_str("\\\\"),
+            '\n' => res.push_str("\\n"),
+            c if c.is_control() => res.push_str(&format!("\\u{:04x}", c as u32)),
+            c => res.push(c),
+        }
+    }
+    res.push('"');
+    res
+}
+
+fn csv_field(txt: &str) -> String {
+    if txt.contains([',', '"', '\n']) {
+        format!("\"{}\"", txt.replace('"', "\"\""))
+    } else {
+        txt.to_string()
+    }
+}
+
+// Time the whole run took, next to the time its days took added up. With
+// several jobs the days overlap, so wall time drops below the summed time.
+pub struct Totals {
+    pub wall: Duration,
+    pub day_sum: Duration,
+    pub jobs: usize,
+}
+
+static TOTAL_COLUMNS: [&str; 3] = ["wall_ns", "day_sum_ns", "jobs"];
+
+impl Totals {
+    fn fields(&self) -> [String; 3] {
+        [
+            self.wall.as_nanos().to_string(),
+            self.day_sum.as_nanos().to_string(),
+            self.jobs.to_string(),
+        ]
+    }
+
+    fn summary(&self) -> String {
+        format!(
+            "Total: wall {:?}, sum of day times {:?} ({} job{})",
+            self.wall,
+            self.day_sum,
+            self.jobs,
+            if self.jobs == 1 { "" } else { "s" }
+        )
+    }
+
+    // What follows the records, machine formats get a table or object of their own
+    fn render(&self, format: Format) -> String {
+        let fields = self.fields();
+        match format {
+            Format::Text => format!("{}\nDONE\n", self.summary()),
+            Format::Json => {
+                let values: Vec<String> = TOTAL_COLUMNS
+                    .iter()
+                    .zip(&fields)
+                    .map(|(name, value)| format!("\"{}\": {}", name, value))
+                    .collect();
+                format!("  ],\n  \"total\": {{{}}}\n}}\n", values.join(", "))
+            }
+            // A second table would break the file, see `Reporter::finish`
+            Format::Csv => String::new(),
+            Format::Markdown => format!(
+                "\n| {} |\n|{}\n| {} |\n",
+                TOTAL_COLUMNS.join(" | "),
+                "---|".repeat(TOTAL_COLUMNS.len()),
+                fields.join(" | ")
+            ),
+        }
+    }
+}
+
+// Prints records as they come in, so a long run still shows progress
+pub struct Reporter {
+    format: Format,
+    records: usize,
+}
+
+impl Reporter {
+    pub fn new(format: Format) -> Reporter {
+        let reporter = Reporter { format, records: 0 };
+        match format {
+            Format::Text => {}
+            Format::Json => println!("{{\n  \"records\": ["),
+            Format::Csv => println!("{}", COLUMNS.join(",")),
+            Format::Markdown => {
+                println!("| {} |", COLUMNS.join(" | "));
+                println!("|{}", "---|".repeat(COLUMNS.len()));
+            }
+        }
+        reporter
+    }
+
+    pub fn record(&mut self, record: &Record) {
+        let fields = record.fields();
+        match self.format {
+            Format::Text => {
+                println!("{}", record.describe());
+                if let Some(memory) = record.describe_memory() {
+                    println!("  memory: {}", memory);
+                }
+                let checks: Vec<String> = record
+                    .verdicts
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(idx, x)| x.as_ref().map(|v| format!("part{} {}", idx + 1, v)))
+                    .collect();
+                if !checks.is_empty() {
+                    println!("  check: {}", checks.join(", "));
+                }
+            }
+            Format::Json => {
+                // Numbers stay numbers, everything else is a string
+                let values: Vec<String> = COLUMNS
+                    .iter()
+                    .zip(fields)
+                    .map(|(&name, field)| {
+                        let value = match field {
+                            None => "null".to_string(),
+                            Some(x) if is_number(name) => x,
+                            Some(x) => json_string(&x),
+                        };
+                        format!("\"{}\": {}", name, value)
+                    })
+                    .collect();
+                let separator = if self.records == 0 { "" } else { ",\n" };
+                print!("{}    {{{}}}", separator, values.join(", "));
+            }
+            Format::Csv => {
+                let values: Vec<String> = fields
+                    .iter()
+                    .map(|x| csv_field(x.as_deref().unwrap_or_default()))
+                    .collect();
+                println!("{}", values.join(","));
+            }
+            Format::Markdown => {
+                let values: Vec<String> = fields
+                    .iter()
+                    .map(|x| x.as_deref().unwrap_or_default().replace('|', "\\|"))
+                    .collect();
+                println!("| {} |", values.join(" | "));
+            }
+        }
+        self.records += 1;
+    }
+
+    pub fn finish(self, totals: &Totals) {
+        // The last JSON record is left open for a separator
+        if self.format == Format::Json && self.records > 0 {
+            println!();
+        }
+        print!("{}", totals.render(self.format));
+        if self.format == Format::Csv {
+            eprintln!("{}", totals.summary());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escaping() {
+        assert_eq!(json_string(r#"a"b\c"#), r#""a\"b\\c""#);
+        assert_eq!(csv_field("bjq,jznhvh"), "\"bjq,jznhvh\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("928"), "928");
+    }
+
+    #[test]
+    fn sizes() {
+        assert_eq!(human_bytes(0), "0 B");
+        assert_eq!(human_bytes(1023), "1023 B");
+        assert_eq!(human_bytes(1536), "1.5 KiB");
+        assert_eq!(human_bytes(8_000_008), "7.6 MiB");
+    }
+
+    #[test]
+    fn missing_fields() {
+        let solution = Solution {
+            part1: None,
+            part2: Some(Answer::UInt(610)),
+            ..Solution::default()
+        };
+        let record = Record {
+            year: 2020,
+            day: 5,
+            input: Some("alice"),
+            solution: &solution,
+            verdicts: [None, Some(Verdict::Pass)],
+        };
+        let fields = record.fields();
+        assert_eq!(fields.len(), COLUMNS.len());
+        assert_eq!(fields[0].as_deref(), Some("2020"));
+        assert_eq!(fields[2].as_deref(), Some("alice"));
+        assert_eq!(fields[3], None);
+        assert_eq!(fields[4].as_deref(), Some("610"));
+        assert_eq!(fields[6], None);
+        assert_eq!(fields[8], None);
+        assert_eq!(fields[18].as_deref(), Some("pass"));
+    }
+
+    #[test]
+    fn totals() {
+        let totals = Totals {
+            wall: Duration::from_millis(3),
+            day_sum: Duration::from_millis(5),
+            jobs: 2,
+        };
+        assert_eq!(
+            totals.render(Format::Text),
+            "Total: wall 3ms, sum of day times 5ms (2 jobs)\nDONE\n"
+        );
+        assert_eq!(
+            totals.render(Format::Json),
+            "  ],\n  \"total\": {\"wall_ns\": 3000000, \"day_sum_ns\": 5000000, \"jobs\": 2}\n}\n"
+        );
+        assert_eq!(totals.render(Format::Csv), "");
+        assert_eq!(
+            totals.render(Format::Markdown),
+            "\n| wall_ns | day_sum_ns | jobs |\n|---|---|---|\n| 3000000 | 5000000 | 2 |\n"
+        );
+    }
+}
@@ -0,0 +1,129 @@
+use rustaocean2020::error::{self, Error};
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(msg) => *msg,
+        Err(payload) => match payload.downcast::<&'static str>() {
+            Ok(msg) => msg.to_string(),
+            Err(_) => "unknown panic".to_string(),
+        },
+    }
+}
+
+// Runs `func`, turning a panic into its message
+pub fn catch_panic<T, F: FnOnce() -> T>(func: F) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(func)).map_err(panic_message)
+}
+
+// A panic caught while solving, reported like any other failed solve
+pub fn panicked<T>(msg: String) -> error::Result<T> {
+    Err(Error::solve(format!("panicked: {}", msg)))
+}
+
+// Solves `tasks` (days, or days paired with an input) on up to `jobs` worker
+// threads. Workers pick the next unsolved task as soon as they're free, while
+// `report` still sees the results in the order of `tasks`, on the calling thread.
+// A task that panics is reported with the panic message, the others still run.
+pub fn run_ordered<J, T, S, R>(tasks: &[J], jobs: usize, solve: S, mut report: R)
+where
+    J: Sync,
+    T: Send,
+    S: Fn(&J) -> T + Sync,
+    R: FnMut(&J, Result<T, String>),
+{
+    let next_task = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, tasks.len().max(1)) {
+            let tx = tx.clone();
+            let next_task = &next_task;
+            let solve = &solve;
+            scope.spawn(move || loop {
+                let idx = next_task.fetch_add(1, Ordering::Relaxed);
+                let task = match tasks.get(idx) {
+                    Some(task) => task,
+                    None => break,
+                };
+                if tx.send((idx, catch_panic(|| solve(task)))).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(tx);
+
+        // Results that arrive early wait here until every task before them is reported
+        let mut pending = BTreeMap::new();
+        let mut next_report = 0;
+        for (idx, result) in rx {
+            pending.insert(idx, result);
+            while let Some(result) = pending.remove(&next_report) {
+                report(&tasks[next_report], result);
+                next_report += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn reports_in_order() {
+        let days: Vec<u8> = (1..=10).collect();
+        let mut reported = Vec::new();
+        // Earlier days take longer, so they finish last
+        run_ordered(
+            &days,
+            4,
+            |&day| {
+                thread::sleep(Duration::from_millis(20 - day as u64));
+                day * 2
+            },
+            |&day, res| reported.push((day, res.unwrap())),
+        );
+        let expected: Vec<(u8, u8)> = days.iter().map(|&x| (x, x * 2)).collect();
+        assert_eq!(reported, expected);
+    }
+
+    #[test]
+    fn survives_panics() {
+        let days: Vec<u8> = (1..=5).collect();
+        let mut reported = Vec::new();
+        run_ordered(
+            &days,
+            2,
+            |&day| match day {
+                2 => panic!("day {} is broken", day),
+                4 => panic!("static message"),
+                _ => day,
+            },
+            |&day, res| reported.push((day, res)),
+        );
+        assert_eq!(
+            reported,
+            vec![
+                (1, Ok(1)),
+                (2, Err("day 2 is broken".to_string())),
+                (3, Ok(3)),
+                (4, Err("static message".to_string())),
+                (5, Ok(5)),
+            ]
+        );
+    }
+
+    #[test]
+    fn no_days() {
+        let mut calls = 0;
+        run_ordered(&[], 4, |&day: &u8| day, |_, _| calls += 1);
+        assert_eq!(calls, 0);
+    }
+}
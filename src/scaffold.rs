@@ -0,0 +1,237 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+use rustaocean2020::common;
+use rustaocean2020::error::Error;
+use rustaocean2020::registry;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+static TEMPLATE: &str = include_str!("day0x.rs");
+
+lazy_static! {
+    static ref YEARS_LIST: Regex =
+        Regex::new(r"(?s)static YEARS: &\[&\[&dyn DaySolver\]\] = &\[(.*?)\];").unwrap();
+    static ref YEAR_ENTRY: Regex = Regex::new(r"^crate::y(\d{4})::SOLVERS$").unwrap();
+}
+
+// rustfmt's limits, so a freshly generated file doesn't show up in `cargo fmt`
+const MAX_WIDTH: usize = 100;
+const ARRAY_WIDTH: usize = 60;
+
+fn src_dir() -> PathBuf {
+    PathBuf::from("src")
+}
+
+fn year_module(year: u16) -> String {
+    format!("y{}", year)
+}
+
+fn day_module(day: u8) -> String {
+    format!("day{:02}", day)
+}
+
+fn day_solver(day: u8) -> String {
+    format!("Day{:02}", day)
+}
+
+fn render_template(year: u16, day: u8) -> String {
+    TEMPLATE
+        .replace("Day0x", &day_solver(day))
+        .replace(
+            "const YEAR: u16 = 2020;",
+            &format!("const YEAR: u16 = {};", year),
+        )
+        .replace("const DAY: u8 = 0;", &format!("const DAY: u8 = {};", day))
+}
+
+// Adds the day to the `days!` list of a year module, keeping it sorted
+fn register_day(mod_text: &str, day: u8) -> Result<String, String> {
+    let entry = format!("    {}::{},", day_module(day), day_solver(day));
+    let mut lines: Vec<&str> = mod_text.lines().collect();
+    let start = lines
+        .iter()
+        .position(|x| x.trim_end() == "days! {")
+        .ok_or("no `days!` list in the year module")?;
+    let len = lines[start + 1..]
+        .iter()
+        .position(|x| x.trim_end() == "}")
+        .ok_or("unterminated `days!` list in the year module")?;
+
+    let days = &lines[start + 1..start + 1 + len];
+    if days.contains(&entry.as_str()) {
+        return Err(format!("{} is already registered", day_module(day)));
+    }
+    let at = start + 1 + days.partition_point(|x| *x < entry.as_str());
+    lines.insert(at, &entry);
+    Ok(lines.join("\n") + "\n")
+}
+
+fn new_year_module(day: u8) -> String {
+    register_day("days! {\n}\n", day).expect("an empty list takes any day")
+}
+
+// Declares the year module in lib.rs, next to the other years
+fn declare_year(lib_text: &str, year: u16) -> String {
+    let entry = format!("pub mod {};", year_module(year));
+    let mut lines: Vec<&str> = lib_text.lines().collect();
+    let at = lines
+        .iter()
+        .rposition(|x| x.starts_with("pub mod ") && *x < entry.as_str())
+        .map_or(lines.len(), |x| x + 1);
+    lines.insert(at, &entry);
+    lines.join("\n") + "\n"
+}
+
+// Adds the year's solvers to the `YEARS` list of the registry
+fn register_year(registry_text: &str, year: u16) -> Result<String, String> {
+    let caps = YEARS_LIST
+        .captures(registry_text)
+        .ok_or("no `YEARS` list in the registry")?;
+    let mut years = Vec::new();
+    for item in caps[1].split(',').map(str::trim).filter(|x| !x.is_empty()) {
+        let num: u16 = YEAR_ENTRY
+            .captures(item)
+            .and_then(|x| x[1].parse().ok())
+            .ok_or_else(|| format!("unexpected entry `{}` in the registry", item))?;
+        years.push(num);
+    }
+    years.push(year);
+    years.sort_unstable();
+    years.dedup();
+
+    let entries: Vec<String> = years
+        .iter()
+        .map(|&x| format!("crate::{}::SOLVERS", year_module(x)))
+        .collect();
+    let items = entries.join(", ");
+    let one_line = format!("static YEARS: &[&[&dyn DaySolver]] = &[{}];", items);
+    let list = if one_line.len() <= MAX_WIDTH && items.len() <= ARRAY_WIDTH {
+        one_line
+    } else {
+        let items: String = entries.iter().map(|x| format!("    {},\n", x)).collect();
+        format!("static YEARS: &[&[&dyn DaySolver]] = &[\n{}];", items)
+    };
+
+    let range = caps.get(0).unwrap().range();
+    Ok(format!(
+        "{}{}{}",
+        &registry_text[..range.start],
+        list,
+        &registry_text[range.end..]
+    ))
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| Error::io(path, e).to_string())
+}
+
+fn write(path: &Path, text: &str) -> Result<(), String> {
+    fs::write(path, text).map_err(|e| Error::io(path, e).to_string())
+}
+
+// Creates the module, input file and test stub of a new day and registers it.
+// Returns the files that were written. Must be run from the repository root,
+// like everything else that reads `inputs/`.
+pub fn new_day(year: u16, day: u8) -> Result<Vec<PathBuf>, String> {
+    let src = src_dir();
+    if !src.join("lib.rs").is_file() {
+        return Err("`new` has to be run from the repository root".to_string());
+    }
+
+    let year_dir = src.join(year_module(year));
+    let module_path = year_dir.join(format!("{}.rs", day_module(day)));
+    if registry::get_solver(year, day).is_some() || module_path.exists() {
+        return Err(format!("day {} of {} already exists", day, year));
+    }
+
+    // Everything is prepared before the first write, so a failure can't leave
+    // the day half registered
+    let mod_path = year_dir.join("mod.rs");
+    let lib_path = src.join("lib.rs");
+    let registry_path = src.join("registry.rs");
+    let mut updates = vec![(module_path, render_template(year, day))];
+    if mod_path.exists() {
+        let text = register_day(&read(&mod_path)?, day)?;
+        updates.push((mod_path, text));
+    } else {
+        updates.push((mod_path, new_year_module(day)));
+        updates.push((lib_path.clone(), declare_year(&read(&lib_path)?, year)));
+        let text = register_year(&read(&registry_path)?, year)?;
+        updates.push((registry_path, text));
+    }
+
+    fs::create_dir_all(&year_dir).map_err(|e| Error::io(&year_dir, e).to_string())?;
+    let mut written = Vec::new();
+    for (path, text) in updates {
+        write(&path, &text)?;
+        written.push(path);
+    }
+
+    // An input that was downloaded before the day was scaffolded is kept
+    let input_path = common::day_input_filename(year, day);
+    if !input_path.exists() {
+        let input_dir = common::year_input_dir(year);
+        fs::create_dir_all(&input_dir).map_err(|e| Error::io(&input_dir, e).to_string())?;
+        write(&input_path, "")?;
+        written.push(input_path);
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_template() {
+        let text = render_template(2021, 7);
+        assert!(text.contains("pub struct Day07;"));
+        assert!(text.contains("impl Solver for Day07 {"));
+        assert!(text.contains("const YEAR: u16 = 2021;"));
+        assert!(text.contains("const DAY: u8 = 7;"));
+        assert!(text.contains("Day07.parse(&example_lines(EXAMPLE))"));
+        assert!(!text.contains("Day0x"));
+    }
+
+    #[test]
+    fn registers_days_in_order() {
+        let text = "days! {\n    day01::Day01,\n    day09::Day09,\n}\n\n#[cfg(test)]\n";
+        let res = register_day(text, 5).unwrap();
+        assert_eq!(
+            res,
+            "days! {\n    day01::Day01,\n    day05::Day05,\n    day09::Day09,\n}\n\n#[cfg(test)]\n"
+        );
+        assert!(register_day(&res, 9).is_err());
+        assert_eq!(new_year_module(3), "days! {\n    day03::Day03,\n}\n");
+    }
+
+    #[test]
+    fn declares_years() {
+        let lib = "#[macro_use]\npub mod common;\npub mod solver;\npub mod y2020;\n";
+        assert_eq!(
+            declare_year(lib, 2021),
+            "#[macro_use]\npub mod common;\npub mod solver;\npub mod y2020;\npub mod y2021;\n"
+        );
+        assert_eq!(
+            declare_year(lib, 2015),
+            "#[macro_use]\npub mod common;\npub mod solver;\npub mod y2015;\npub mod y2020;\n"
+        );
+
+        let registry = "use x;\n\nstatic YEARS: &[&[&dyn DaySolver]] = &[crate::y2020::SOLVERS];\n";
+        let res = register_year(registry, 2015).unwrap();
+        assert_eq!(
+            res,
+            "use x;\n\nstatic YEARS: &[&[&dyn DaySolver]] = &[crate::y2015::SOLVERS, crate::y2020::SOLVERS];\n"
+        );
+        // Too long for one line, so it's split like rustfmt would
+        let res = register_year(&res, 2016).unwrap();
+        assert!(res.contains("&[\n    crate::y2015::SOLVERS,\n    crate::y2016::SOLVERS,\n"));
+        assert_eq!(
+            register_year(&res, 2017)
+                .unwrap()
+                .matches("SOLVERS")
+                .count(),
+            4
+        );
+    }
+}
@@ -0,0 +1,116 @@
+use crate::alloc::{self, AllocStats};
+use crate::common::{Answer, Part};
+use crate::error::Result;
+use std::time::{Duration, Instant};
+
+pub trait Solver {
+    // Event and puzzle day the solver is registered under
+    const YEAR: u16;
+    const DAY: u8;
+    type Input;
+    type Answer1: Into<Answer>;
+    type Answer2: Into<Answer>;
+
+    fn parse(&self, raw_input: &[String]) -> Result<Self::Input>;
+    fn part1(&self, input: &Self::Input) -> Result<Self::Answer1>;
+    fn part2(&self, input: &Self::Input) -> Result<Self::Answer2>;
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Option<Duration>,
+    pub part2: Option<Duration>,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1.unwrap_or_default() + self.part2.unwrap_or_default()
+    }
+}
+
+// Only measured when built with the `alloc-stats` feature
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Allocations {
+    pub parse: AllocStats,
+    pub part1: Option<AllocStats>,
+    pub part2: Option<AllocStats>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Solution {
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+    pub timings: Timings,
+    pub allocations: Option<Allocations>,
+}
+
+// Object safe counterpart of `Solver`, so days with different inputs can share one registry
+pub trait DaySolver: Sync {
+    fn year(&self) -> u16;
+    fn day(&self) -> u8;
+    fn run(&self, raw_input: &[String], part: Option<Part>) -> Result<Solution>;
+}
+
+fn timed<T, F: FnOnce() -> T>(func: F) -> (T, Duration) {
+    let now = Instant::now();
+    let res = func();
+    (res, now.elapsed())
+}
+
+// The clock runs inside, so counting allocations doesn't add to the time
+fn measured<T, F: FnOnce() -> T>(func: F) -> (T, Duration, Option<AllocStats>) {
+    let ((res, time), allocs) = alloc::measure(|| timed(func));
+    (res, time, allocs)
+}
+
+impl<S> DaySolver for S
+where
+    S: Solver + Sync,
+{
+    fn year(&self) -> u16 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn run(&self, raw_input: &[String], part: Option<Part>) -> Result<Solution> {
+        let (input, parse_time, parse_allocs) = measured(|| self.parse(raw_input));
+        let input = input?;
+        let mut solution = Solution {
+            timings: Timings {
+                parse: parse_time,
+                ..Timings::default()
+            },
+            allocations: parse_allocs.map(|parse| Allocations {
+                parse,
+                ..Allocations::default()
+            }),
+            ..Solution::default()
+        };
+
+        if part != Some(Part::Two) {
+            let (answer, time, allocs) = measured(|| self.part1(&input));
+            solution.part1 = Some(answer?.into());
+            solution.timings.part1 = Some(time);
+            if let Some(allocations) = solution.allocations.as_mut() {
+                allocations.part1 = allocs;
+            }
+        }
+        if part != Some(Part::One) {
+            let (answer, time, allocs) = measured(|| self.part2(&input));
+            // Leaves no answer to print or check for a part without a puzzle
+            solution.part2 = match answer?.into() {
+                Answer::Unit => None,
+                answer => Some(answer),
+            };
+            solution.timings.part2 = Some(time);
+            if let Some(allocations) = solution.allocations.as_mut() {
+                allocations.part2 = allocs;
+            }
+        }
+        Ok(solution)
+    }
+}
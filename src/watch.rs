@@ -0,0 +1,327 @@
+use crate::answers::{self, Answers};
+use crate::cli::{Args, InputSource, WatchArgs};
+use crate::report::{Format, Record, Reporter};
+use crate::runner;
+use rustaocean2020::common::{self, Answer, Part};
+use rustaocean2020::error::{Error, Result};
+use rustaocean2020::registry;
+use rustaocean2020::solver::Solution;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::SystemTime;
+
+// Modification time and size, so a change within the same second still shows
+type Stamp = Option<(SystemTime, u64)>;
+
+fn stamp(path: &Path) -> Stamp {
+    let meta = fs::metadata(path).ok()?;
+    Some((meta.modified().ok()?, meta.len()))
+}
+
+// Every source file under `dir`, in a stable order
+fn source_stamps(dir: &Path) -> Vec<(PathBuf, Stamp)> {
+    let mut res = Vec::new();
+    let mut dirs = vec![dir.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        let entries = match fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(_) => continue,
+        };
+        for path in entries.filter_map(|x| x.ok().map(|x| x.path())) {
+            if path.is_dir() {
+                dirs.push(path);
+            } else if path.extension().is_some_and(|x| x == "rs") {
+                let file_stamp = stamp(&path);
+                res.push((path, file_stamp));
+            }
+        }
+    }
+    res.sort();
+    res
+}
+
+// Builds the binary the way the running one was built
+fn cargo_args() -> Vec<&'static str> {
+    let mut args = vec!["build", "--bin", env!("CARGO_PKG_NAME")];
+    if !cfg!(debug_assertions) {
+        args.push("--release");
+    }
+    if cfg!(feature = "alloc-stats") {
+        args.extend(["--features", "alloc-stats"]);
+    }
+    args
+}
+
+fn rebuild() -> bool {
+    println!("Rebuilding: cargo {}", cargo_args().join(" "));
+    match Command::new("cargo").args(cargo_args()).status() {
+        Ok(status) => status.success(),
+        Err(err) => {
+            eprintln!("error: cannot run cargo: {}", err);
+            false
+        }
+    }
+}
+
+// Replaces this process with the rebuilt binary, only returns if that fails
+#[cfg(unix)]
+fn restart(exe: &Path) -> io::Error {
+    use std::os::unix::process::CommandExt;
+    Command::new(exe).args(env::args_os().skip(1)).exec()
+}
+
+#[cfg(not(unix))]
+fn restart(exe: &Path) -> io::Error {
+    match Command::new(exe).args(env::args_os().skip(1)).status() {
+        Ok(status) => std::process::exit(status.code().unwrap_or(1)),
+        Err(err) => err,
+    }
+}
+
+fn show(answer: &Option<Answer>) -> String {
+    answer.as_ref().map_or("-".to_string(), Answer::to_string)
+}
+
+fn answer_changes(prev: &Solution, cur: &Solution) -> Vec<String> {
+    let parts = [
+        ("part1", &prev.part1, &cur.part1),
+        ("part2", &prev.part2, &cur.part2),
+    ];
+    parts
+        .iter()
+        .filter(|(_, prev, cur)| prev != cur)
+        .map(|(name, prev, cur)| format!("{} {} -> {}", name, show(prev), show(cur)))
+        .collect()
+}
+
+fn timing_changes(prev: &Solution, cur: &Solution) -> Vec<String> {
+    let (prev, cur) = (&prev.timings, &cur.timings);
+    let phases = [
+        ("parse", Some(prev.parse), Some(cur.parse)),
+        ("part1", prev.part1, cur.part1),
+        ("part2", prev.part2, cur.part2),
+        ("total", Some(prev.total()), Some(cur.total())),
+    ];
+    phases
+        .iter()
+        .filter_map(|&(name, prev, cur)| {
+            let (prev, cur) = (prev?, cur?);
+            let change = match prev.is_zero() {
+                true => String::new(),
+                false => format!(
+                    " ({:+.1}%)",
+                    (cur.as_secs_f64() / prev.as_secs_f64() - 1.0) * 100.0
+                ),
+            };
+            Some(format!("{} {:.2?} -> {:.2?}{}", name, prev, cur, change))
+        })
+        .collect()
+}
+
+fn solve(args: &Args, day: u8, input_path: &Path) -> Result<Solution> {
+    let solver = registry::get_solver(args.year, day)
+        .ok_or_else(|| Error::solve("no solver for this day"))?;
+    let input = common::get_input(input_path)?;
+    solver.run(&input, args.part)
+}
+
+// Answers are reloaded every run, fixing a wrong one is a reason to re-run
+fn check(
+    answers_path: Option<&PathBuf>,
+    day: u8,
+    solution: &Solution,
+) -> Result<[Option<answers::Verdict>; 2]> {
+    let mut verdicts = [None, None];
+    if let Some(path) = answers_path {
+        let answers = Answers::load(path)?;
+        let parts = [(Part::One, &solution.part1), (Part::Two, &solution.part2)];
+        for (verdict, (part, answer)) in verdicts.iter_mut().zip(parts) {
+            *verdict = answer
+                .as_ref()
+                .map(|x| answers.check(day, part, &x.to_string()));
+        }
+    }
+    Ok(verdicts)
+}
+
+// Runs the day once and then again whenever its input or answers change,
+// until interrupted. A change to the sources rebuilds the binary and restarts
+// it with the same arguments, a failed build keeps the old one watching.
+pub fn watch(args: &Args, watch_args: &WatchArgs) -> bool {
+    let day = args.days[0];
+    let (input_path, answers_path) = match &args.input {
+        InputSource::File(path) => (path.clone(), None),
+        _ => (
+            common::day_input_filename(args.year, day),
+            Some(answers::default_path(args.year)),
+        ),
+    };
+    let source_dir = Path::new("src");
+    // Looked up before a rebuild replaces the file
+    let exe = match env::current_exe() {
+        Ok(exe) => exe,
+        Err(err) => {
+            eprintln!("error: cannot find the running binary: {}", err);
+            return true;
+        }
+    };
+
+    let mut watched = vec![&input_path];
+    watched.extend(answers_path.as_ref());
+    let mut stamps: Vec<Stamp> = watched.iter().map(|x| stamp(x)).collect();
+    let mut sources = source_stamps(source_dir);
+    let mut previous: Option<Solution> = None;
+    let mut reporter = Reporter::new(Format::Text);
+
+    println!(
+        "Watching {} and {} every {:?}, stop with Ctrl-C",
+        watched
+            .iter()
+            .map(|x| x.display().to_string())
+            .collect::<Vec<_>>()
+            .join(", "),
+        source_dir.display(),
+        watch_args.interval
+    );
+    let mut changed = vec![];
+    loop {
+        if !changed.is_empty() {
+            println!("\nChanged: {}", changed.join(", "));
+        }
+        // A panicking solver is just a failed run, the session goes on
+        let result = runner::catch_panic(|| solve(args, day, &input_path))
+            .unwrap_or_else(runner::panicked)
+            .and_then(|solution| {
+                check(answers_path.as_ref(), day, &solution).map(|verdicts| (solution, verdicts))
+            });
+        match result {
+            Ok((solution, verdicts)) => {
+                reporter.record(&Record {
+                    year: args.year,
+                    day,
+                    input: None,
+                    solution: &solution,
+                    verdicts,
+                });
+                if let Some(prev) = &previous {
+                    let answers = answer_changes(prev, &solution);
+                    match answers.is_empty() {
+                        true => println!("  answers: unchanged"),
+                        false => println!("  answers: {}", answers.join(", ")),
+                    }
+                    println!("  timings: {}", timing_changes(prev, &solution).join(", "));
+                }
+                previous = Some(solution);
+            }
+            Err(err) => eprintln!("error: {}", err.for_day(day)),
+        }
+
+        // Sleep until one of the watched files looks different
+        loop {
+            thread::sleep(watch_args.interval);
+            let current = source_stamps(source_dir);
+            if current != sources {
+                sources = current;
+                println!("\n{} changed", source_dir.display());
+                if rebuild() {
+                    let err = restart(&exe);
+                    eprintln!("error: cannot restart {}: {}", exe.display(), err);
+                } else {
+                    eprintln!("error: build failed, still running the previous build");
+                }
+            }
+
+            let current: Vec<Stamp> = watched.iter().map(|x| stamp(x)).collect();
+            changed = watched
+                .iter()
+                .zip(current.iter().zip(&stamps))
+                .filter(|(_, (cur, prev))| cur != prev)
+                .map(|(path, _)| path.display().to_string())
+                .collect();
+            stamps = current;
+            if !changed.is_empty() {
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustaocean2020::solver::Timings;
+    use std::time::Duration;
+
+    fn solution(part1: u64, part2: Option<u64>, millis: [u64; 3]) -> Solution {
+        Solution {
+            part1: Some(part1.into()),
+            part2: part2.map(Answer::from),
+            timings: Timings {
+                parse: Duration::from_millis(millis[0]),
+                part1: Some(Duration::from_millis(millis[1])),
+                part2: part2.map(|_| Duration::from_millis(millis[2])),
+            },
+            allocations: None,
+        }
+    }
+
+    #[test]
+    fn diffs_answers() {
+        let prev = solution(1782, Some(797), [10, 10, 10]);
+        let cur = solution(1790, Some(797), [10, 10, 10]);
+        assert_eq!(answer_changes(&prev, &cur), vec!["part1 1782 -> 1790"]);
+        assert!(answer_changes(&prev, &prev).is_empty());
+
+        let cur = solution(1782, None, [10, 10, 10]);
+        assert_eq!(answer_changes(&prev, &cur), vec!["part2 797 -> -"]);
+    }
+
+    #[test]
+    fn diffs_timings() {
+        let prev = solution(5, Some(8), [10, 20, 10]);
+        let cur = solution(5, Some(8), [5, 30, 5]);
+        assert_eq!(
+            timing_changes(&prev, &cur),
+            vec![
+                "parse 10.00ms -> 5.00ms (-50.0%)",
+                "part1 20.00ms -> 30.00ms (+50.0%)",
+                "part2 10.00ms -> 5.00ms (-50.0%)",
+                "total 40.00ms -> 40.00ms (+0.0%)",
+            ]
+        );
+        // Phases that only ran once have nothing to compare against
+        let cur = solution(5, None, [10, 20, 0]);
+        assert_eq!(timing_changes(&prev, &cur).len(), 3);
+    }
+
+    #[test]
+    fn lists_sources() {
+        let dir = env::temp_dir().join(format!("watch_sources_{}", std::process::id()));
+        fs::create_dir_all(dir.join("y2020")).unwrap();
+        for file in ["main.rs", "notes.txt", "y2020/day01.rs"] {
+            fs::write(dir.join(file), "fn main() {}").unwrap();
+        }
+        let sources = source_stamps(&dir);
+        let paths: Vec<&PathBuf> = sources.iter().map(|(path, _)| path).collect();
+        assert_eq!(
+            paths,
+            vec![&dir.join("main.rs"), &dir.join("y2020/day01.rs")]
+        );
+        assert!(sources.iter().all(|(_, stamp)| stamp.is_some()));
+
+        fs::write(dir.join("main.rs"), "fn main() { let x = 1; }").unwrap();
+        assert_ne!(source_stamps(&dir), sources);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn builds_like_this_binary() {
+        let args = cargo_args();
+        assert_eq!(&args[..3], ["build", "--bin", "rustaocean2020"]);
+        assert_eq!(args.contains(&"--release"), !cfg!(debug_assertions));
+    }
+}
@@ -0,0 +1,102 @@
+use crate::common::{parse_lines, parse_value};
+use crate::error::{Error, Result};
+use crate::solver::Solver;
+use itertools::Itertools;
+use std::collections::HashSet;
+
+// Unused generic variant, far slower than the specialized ones below
+fn _find_combination_of(input: &[i32], comb_size: usize) -> i32 {
+    for vals in input.iter().copied().combinations(comb_size) {
+        if vals.iter().sum::<i32>() == 2020 {
+            return vals.iter().product();
+        }
+    }
+    0
+}
+
+// Unused quadratic variant of `find_combination_of2`
+fn _find_combination_of1(input: &[i32], _: usize) -> i32 {
+    for (idx, val) in input.iter().enumerate() {
+        for val2 in input.iter().skip(idx + 1) {
+            if val + val2 == 2020 {
+                return val * val2;
+            }
+        }
+    }
+    0
+}
+
+fn find_combination_of2<'a, I>(input_iter: I, target: i32) -> Option<i32>
+where
+    I: Iterator<Item = &'a i32>,
+{
+    let mut complements: HashSet<i32> = HashSet::new();
+    for val in input_iter {
+        let complement = target - val;
+        if complements.contains(&complement) {
+            return Some(complement * val);
+        }
+        complements.insert(*val);
+    }
+    None
+}
+
+fn find_combination_of3(input: &[i32], target: i32) -> Option<i32> {
+    for (idx, val) in input.iter().enumerate() {
+        if let Some(prod) = find_combination_of2(input.iter().skip(idx + 1), target - val) {
+            return Some(prod * val);
+        }
+    }
+
+    None
+}
+
+pub struct Day01;
+
+impl Solver for Day01 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 1;
+    type Input = Vec<i32>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(&self, raw_input: &[String]) -> Result<Self::Input> {
+        parse_lines(raw_input, parse_value)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Answer1> {
+        find_combination_of2(input.iter(), 2020)
+            .ok_or_else(|| Error::solve("no two entries sum to 2020"))
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Answer2> {
+        find_combination_of3(input, 2020)
+            .ok_or_else(|| Error::solve("no three entries sum to 2020"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::example_lines;
+
+    static EXAMPLE: &str = "\
+1721
+979
+366
+299
+675
+1456";
+
+    #[test]
+    fn part1_example() {
+        let input = Day01.parse(&example_lines(EXAMPLE)).unwrap();
+        assert_eq!(Day01.part1(&input).unwrap(), 514579);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day01.parse(&example_lines(EXAMPLE)).unwrap();
+        assert_eq!(Day01.part2(&input).unwrap(), 241861950);
+    }
+}
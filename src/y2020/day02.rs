@@ -0,0 +1,109 @@
+use crate::common::{capture, extract, parse_lines, FromCaptures};
+use crate::error::{Error, Result};
+use crate::solver::Solver;
+use lazy_static::lazy_static;
+use regex::{Captures, Regex};
+use std::str::FromStr;
+
+lazy_static! {
+    static ref PARSE_PATTERN: Regex = Regex::new(r"(\d+)-(\d+) (\w): (\w+)").unwrap();
+}
+
+pub struct PasswordCheck {
+    min: usize,
+    max: usize,
+    character: char,
+    password: String,
+}
+
+impl FromCaptures for PasswordCheck {
+    fn from_captures(caps: &Captures) -> Result<Self> {
+        let (min, max) = (capture(caps, 1)?, capture(caps, 2)?);
+        if min == 0 || min > max {
+            return Err(Error::parse(format!(
+                "invalid policy range {}-{}",
+                min, max
+            )));
+        }
+
+        Ok(PasswordCheck {
+            min,
+            max,
+            character: capture(caps, 3)?,
+            password: capture(caps, 4)?,
+        })
+    }
+}
+
+impl FromStr for PasswordCheck {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        extract(&PARSE_PATTERN, s)
+    }
+}
+
+impl PasswordCheck {
+    fn check(&self) -> bool {
+        let count = self.password.matches(self.character).count();
+        count >= self.min && count <= self.max
+    }
+
+    fn check_slice(&self) -> bool {
+        let mut pass_chars = self.password.chars();
+        let first_matches = pass_chars.nth(self.min - 1) == Some(self.character);
+        let second_matches = match self.max - self.min {
+            0 => first_matches,
+            diff => pass_chars.nth(diff - 1) == Some(self.character),
+        };
+        first_matches ^ second_matches
+    }
+}
+
+pub struct Day02;
+
+impl Solver for Day02 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 2;
+    type Input = Vec<PasswordCheck>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, raw_input: &[String]) -> Result<Self::Input> {
+        parse_lines(raw_input, str::parse)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Answer1> {
+        Ok(input.iter().filter(|&x| x.check()).count())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Answer2> {
+        Ok(input
+            .iter()
+            .filter(|&x| x.check_slice())
+            .count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::example_lines;
+
+    static EXAMPLE: &str = "\
+1-3 a: abcde
+1-3 b: cdefg
+2-9 c: ccccccccc";
+
+    #[test]
+    fn part1_example() {
+        let input = Day02.parse(&example_lines(EXAMPLE)).unwrap();
+        assert_eq!(Day02.part1(&input).unwrap(), 2);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day02.parse(&example_lines(EXAMPLE)).unwrap();
+        assert_eq!(Day02.part2(&input).unwrap(), 1);
+    }
+}
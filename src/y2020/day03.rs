@@ -0,0 +1,93 @@
+use crate::error::Result;
+use crate::grid::Grid;
+use crate::solver::Solver;
+
+// Trees are true, the map repeats to the right
+pub type Map = Grid<bool>;
+
+// Unused variant of `check_slope`
+fn _check_slope2(data: &Map, xslope: usize, yslope: usize) -> usize {
+    let x_limit = data.width();
+    let y_limit = data.height();
+    let slope_generator =
+        itertools::iterate((0, 0), move |(x, y)| ((x + xslope) % x_limit, y + yslope));
+
+    slope_generator
+        .take_while(|&(_, y)| y < y_limit)
+        .filter(|&(x, y)| data[(x, y)])
+        .count()
+}
+
+fn check_slope(data: &Map, xslope: usize, yslope: usize) -> usize {
+    (0..data.height())
+        .step_by(yslope)
+        .enumerate()
+        .filter(|&(step, y)| *data.get_wrapping((step * xslope) as isize, y as isize))
+        .count()
+}
+
+pub struct Day03;
+
+impl Solver for Day03 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 3;
+    type Input = Map;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, raw_input: &[String]) -> Result<Self::Input> {
+        Grid::parse(raw_input, |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Answer1> {
+        Ok(check_slope(input, 3, 1))
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Answer2> {
+        Ok([(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)]
+            .iter()
+            .map(|&(x, y)| check_slope(input, x, y))
+            .product::<usize>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::example_lines;
+
+    static EXAMPLE: &str = "\
+..##.......
+#...#...#..
+.#....#..#.
+..#.#...#.#
+.#...##..#.
+..#.##.....
+.#.#.#....#
+.#........#
+#.##...#...
+#...##....#
+.#..#...#.#";
+
+    #[test]
+    fn part1_example() {
+        let input = Day03.parse(&example_lines(EXAMPLE)).unwrap();
+        assert_eq!(Day03.part1(&input).unwrap(), 7);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day03.parse(&example_lines(EXAMPLE)).unwrap();
+        assert_eq!(Day03.part2(&input).unwrap(), 336);
+    }
+
+    #[test]
+    fn empty_map() {
+        let err = Day03.parse(&example_lines("\n\n")).unwrap_err();
+        assert_eq!(err.line(), Some(1));
+    }
+}
@@ -0,0 +1,201 @@
+use crate::common::{extract, split_sections, Section};
+use crate::error::{Error, Result};
+use crate::solver::Solver;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::{collections::HashMap, collections::HashSet, str::FromStr};
+
+pub struct Passport {
+    data: HashMap<String, String>,
+}
+
+impl FromStr for Passport {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        lazy_static! {
+            static ref PASSPORT: Regex = Regex::new(r"(\w+):(.+)").unwrap();
+        }
+        let m = s
+            .split_whitespace()
+            .map(|x| extract(&PASSPORT, x))
+            .collect::<Result<HashMap<String, String>>>()?;
+
+        Ok(Passport::new(m))
+    }
+}
+
+lazy_static! {
+    static ref FIELDS: HashSet<String> = [
+        "byr".to_string(),
+        "iyr".to_string(),
+        "eyr".to_string(),
+        "hgt".to_string(),
+        "hcl".to_string(),
+        "ecl".to_string(),
+        "pid".to_string(),
+    ]
+    .iter()
+    .cloned()
+    .collect();
+    static ref FOUR_DIG: Regex = Regex::new(r"^\d{4}$").unwrap();
+    static ref NINE_DIG: Regex = Regex::new(r"^\d{9}$").unwrap();
+    static ref HGT: Regex = Regex::new(r"^\d+(cm|in)$").unwrap();
+    static ref HCL: Regex = Regex::new(r"^#[a-f0-9]{6}$").unwrap();
+    static ref ECL: Regex = Regex::new(r"^(amb|b(lu|rn)|gr[yn]|hzl|oth)$").unwrap();
+}
+
+impl Passport {
+    fn new(d: HashMap<String, String>) -> Passport {
+        Passport { data: d }
+    }
+
+    fn verify_four_dig(&self, txt: &str, min: u32, max: u32) -> bool {
+        if !FOUR_DIG.is_match(txt) {
+            return false;
+        }
+        match txt.parse::<u32>() {
+            Ok(val) => (min..=max).contains(&val),
+            Err(_) => false,
+        }
+    }
+
+    fn verify_hgt(&self, txt: &str) -> bool {
+        if !HGT.is_match(txt) {
+            return false;
+        }
+        let val = match txt[..txt.len() - 2].parse::<u32>() {
+            Ok(val) => val,
+            Err(_) => return false,
+        };
+        match txt.ends_with("cm") {
+            true => (150..=193).contains(&val),
+            false => (59..=76).contains(&val),
+        }
+    }
+
+    fn validate_fields(&self) -> bool {
+        let valid_fields = self
+            .data
+            .iter()
+            .filter(|&(key, val)| match key.as_str() {
+                "byr" => self.verify_four_dig(val, 1920, 2002),
+                "iyr" => self.verify_four_dig(val, 2010, 2020),
+                "eyr" => self.verify_four_dig(val, 2020, 2030),
+                "hgt" => self.verify_hgt(val),
+                "hcl" => HCL.is_match(val),
+                "ecl" => ECL.is_match(val),
+                "pid" => NINE_DIG.is_match(val),
+                _ => false,
+            })
+            .count();
+
+        valid_fields == FIELDS.len()
+    }
+
+    fn is_valid(&self) -> bool {
+        let proper_fields = self.data.keys().filter(|&k| FIELDS.contains(k)).count();
+        proper_fields == FIELDS.len()
+    }
+}
+
+// A passport spans all lines of its record
+fn parse_passport(section: &Section) -> Result<Passport> {
+    let mut data = HashMap::new();
+    for passport in section.parse_lines(str::parse::<Passport>)? {
+        data.extend(passport.data);
+    }
+    Ok(Passport::new(data))
+}
+
+pub struct Day04;
+
+impl Solver for Day04 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 4;
+    type Input = Vec<Passport>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, raw_input: &[String]) -> Result<Self::Input> {
+        split_sections(raw_input)
+            .iter()
+            .map(parse_passport)
+            .collect()
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Answer1> {
+        Ok(input.iter().filter(|&x| x.is_valid()).count())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Answer2> {
+        Ok(input
+            .iter()
+            .filter(|&x| x.validate_fields())
+            .count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::example_lines;
+
+    static EXAMPLE: &str = "\
+ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
+byr:1937 iyr:2017 cid:147 hgt:183cm
+
+iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884
+hcl:#cfa07d byr:1929
+
+hcl:#ae17e1 iyr:2013
+eyr:2024
+ecl:brn pid:760753108 byr:1931
+hgt:179cm
+
+hcl:#cfa07d eyr:2025 pid:166559648
+iyr:2011 ecl:brn hgt:59in";
+
+    static INVALID: &str = "\
+eyr:1972 cid:100
+hcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018 byr:1926
+
+iyr:2019
+hcl:#602927 eyr:1967 hgt:170cm
+ecl:grn pid:012533040 byr:1946
+
+hcl:dab227 iyr:2012
+ecl:brn hgt:182cm pid:021572410 eyr:2020 byr:1992 cid:277
+
+hgt:59cm ecl:zzz
+eyr:2038 hcl:74454a iyr:2023
+pid:3556412378 byr:2007";
+
+    static VALID: &str = "\
+pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980
+hcl:#623a2f
+
+eyr:2029 ecl:blu cid:129 byr:1989
+iyr:2014 pid:896056539 hcl:#a97842 hgt:165cm
+
+hcl:#888785
+hgt:164cm byr:2001 iyr:2015 cid:88
+pid:545766238 ecl:hzl
+eyr:2022
+
+iyr:2010 hgt:158cm hcl:#b6652a ecl:blu byr:1944 eyr:2021 pid:093154719";
+
+    #[test]
+    fn part1_example() {
+        let input = Day04.parse(&example_lines(EXAMPLE)).unwrap();
+        assert_eq!(Day04.part1(&input).unwrap(), 2);
+    }
+
+    #[test]
+    fn part2_examples() {
+        let input = Day04.parse(&example_lines(INVALID)).unwrap();
+        assert_eq!(Day04.part2(&input).unwrap(), 0);
+        let input = Day04.parse(&example_lines(VALID)).unwrap();
+        assert_eq!(Day04.part2(&input).unwrap(), 4);
+    }
+}
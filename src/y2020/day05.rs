@@ -0,0 +1,149 @@
+use crate::common::{extract, parse_lines};
+use crate::error::{Error, Result};
+use crate::solver::Solver;
+use itertools::Itertools;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    static ref SEAT_PATTERN: Regex = Regex::new(r"^([FB]{7})([LR]{3})$").unwrap();
+}
+
+pub struct Ticket {
+    code: String,
+    _row: String,
+    _column: String,
+}
+
+fn _bst_code(code: &str, limits: (u32, u32), mapping: (char, char)) -> u32 {
+    let (mut min, mut max) = limits;
+    let (lower, upper) = mapping;
+    for id in code.chars() {
+        match id {
+            u if u == upper => min = (min + max) / 2 + 1,
+            l if l == lower => max = (min + max) / 2,
+            _ => {}
+        }
+    }
+    max
+}
+
+fn shift_code(code: &str) -> u32 {
+    let mut res = 0;
+    for id in code.bytes() {
+        res <<= 1;
+        if id == b'B' || id == b'R' {
+            res |= 0x1;
+        }
+    }
+    res
+}
+
+// Unused variant of `count_seat_id` decoding row and column separately
+fn _count_seat_id2(seat_code: &Ticket) -> u32 {
+    let row = _bst_code(&seat_code._row, (0, 127), ('F', 'B'));
+    let column = _bst_code(&seat_code._column, (0, 7), ('L', 'R'));
+
+    row * 8 + column
+}
+
+fn count_seat_id(seat_code: &Ticket) -> u32 {
+    shift_code(&seat_code.code)
+}
+
+// Unused variant of `find_seat` that sorts the seat ids
+fn _find_seat2(input: &[Ticket]) -> Option<u32> {
+    input
+        .iter()
+        .map(|x| shift_code(&x.code))
+        .sorted()
+        .tuple_windows()
+        .find(|(before, after)| after - before == 2)
+        .map(|(before, _)| before + 1)
+}
+
+fn find_seat(input: &[Ticket]) -> Option<u32> {
+    let mut seats = [false; 128 * 8];
+    input
+        .iter()
+        .for_each(|x| seats[shift_code(&x.code) as usize] = true);
+
+    seats
+        .iter()
+        .enumerate()
+        .skip_while(|(_, &x)| !x)
+        .find(|(_, &x)| !x)
+        .map(|(idx, _)| idx as u32)
+}
+
+pub struct Day05;
+
+impl Solver for Day05 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 5;
+    type Input = Vec<Ticket>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(&self, raw_input: &[String]) -> Result<Self::Input> {
+        parse_lines(raw_input, |x| {
+            let (_row, _column) = extract(&SEAT_PATTERN, x)?;
+            Ok(Ticket {
+                code: x.to_string(),
+                _row,
+                _column,
+            })
+        })
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Answer1> {
+        input
+            .iter()
+            .map(count_seat_id)
+            .max()
+            .ok_or_else(|| Error::solve("no boarding passes"))
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Answer2> {
+        find_seat(input)
+            .ok_or_else(|| Error::solve("no free seat between two taken ones"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::example_lines;
+
+    static EXAMPLE: &str = "\
+BFFFBBFRRR
+FFFBBBFRRR
+BBFFBBFRLL";
+
+    #[test]
+    fn seat_ids() {
+        let input = Day05.parse(&example_lines(EXAMPLE)).unwrap();
+        let ids: Vec<u32> = input.iter().map(count_seat_id).collect();
+        assert_eq!(ids, vec![567, 119, 820]);
+        let ids: Vec<u32> = input.iter().map(_count_seat_id2).collect();
+        assert_eq!(ids, vec![567, 119, 820]);
+    }
+
+    #[test]
+    fn part1_example() {
+        let input = Day05.parse(&example_lines(EXAMPLE)).unwrap();
+        assert_eq!(Day05.part1(&input).unwrap(), 820);
+    }
+
+    // The puzzle has no example for part 2, seats 8 to 12 with 10 missing stand in for one
+    #[test]
+    fn part2_gap() {
+        let input = Day05
+            .parse(&example_lines(
+                "FFFFFFBLLL\nFFFFFFBLLR\nFFFFFFBLRR\nFFFFFFBRLL",
+            ))
+            .unwrap();
+        assert_eq!(Day05.part2(&input).unwrap(), 10);
+        assert_eq!(_find_seat2(&input), Some(10));
+    }
+}
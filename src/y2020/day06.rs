@@ -0,0 +1,104 @@
+use crate::common::split_sections;
+use crate::error::{Error, Result};
+use crate::solver::Solver;
+use std::collections::HashSet;
+
+// Unused HashSet based variant of `count_chars` for part 1
+fn _count_chars2(txt: &[String]) -> usize {
+    txt.iter()
+        .flat_map(String::as_bytes)
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+// Unused HashSet based variant of `count_chars` for part 2
+fn _count_dup_chars2(txt: &[String]) -> usize {
+    txt.iter()
+        .map(|s| s.chars().collect::<HashSet<_>>())
+        .fold(None, |acc, hs| {
+            acc.map(|a: HashSet<_>| a.intersection(&hs).copied().collect())
+                .or(Some(hs))
+        })
+        .map_or(0, |x| x.len())
+}
+
+fn count_chars(txt: &[String], limit: usize) -> usize {
+    let mut char_counter = [0; 26];
+    txt.iter()
+        .flat_map(String::as_bytes)
+        .for_each(|x| char_counter[(x - b'a') as usize] += 1);
+
+    char_counter.iter().filter(|&&x| x >= limit).count()
+}
+
+pub struct Day06;
+
+impl Solver for Day06 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 6;
+    type Input = Vec<Vec<String>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, raw_input: &[String]) -> Result<Self::Input> {
+        // Groups are separated by blank lines, with one person per line
+        split_sections(raw_input)
+            .iter()
+            .map(|group| {
+                group.parse_lines(|x| match x.chars().find(|c| !c.is_ascii_lowercase()) {
+                    Some(c) => Err(Error::parse(format!("unexpected answer `{}`", c))),
+                    None => Ok(x.to_string()),
+                })
+            })
+            .collect()
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Answer1> {
+        Ok(input
+            .iter()
+            .map(|s| count_chars(s, 1))
+            .sum::<usize>())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Answer2> {
+        Ok(input
+            .iter()
+            .map(|s| count_chars(s, s.len()))
+            .sum::<usize>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::example_lines;
+
+    static EXAMPLE: &str = "\
+abc
+
+a
+b
+c
+
+ab
+ac
+
+a
+a
+a
+a
+
+b";
+
+    #[test]
+    fn part1_example() {
+        let input = Day06.parse(&example_lines(EXAMPLE)).unwrap();
+        assert_eq!(Day06.part1(&input).unwrap(), 11);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day06.parse(&example_lines(EXAMPLE)).unwrap();
+        assert_eq!(Day06.part2(&input).unwrap(), 6);
+    }
+}
@@ -0,0 +1,224 @@
+use crate::common::{extract, parse_lines};
+use crate::error::{Error, Result};
+use crate::solver::Solver;
+use lazy_static::lazy_static;
+use queues::*;
+use regex::{Regex, RegexSet};
+use std::collections::{HashMap, HashSet};
+
+lazy_static! {
+    static ref RULE_START: Regex =
+        Regex::new(r"(\w+ \w+) bags? contain (\d+) (\w+ \w+) bags?").unwrap();
+    static ref RULE_END: Regex = Regex::new(r"(\d+) (\w+ \w+) bags?").unwrap();
+    static ref RULE_EMPTY: Regex = Regex::new(r"(\w+ \w+) contain no other bags").unwrap();
+    static ref REGS: RegexSet = RegexSet::new([
+        r"(\w+ \w+) bags? contain (\d+) (\w+ \w+) bags?,",
+        r"(\w+ \w+) bags? contain (\d+) (\w+ \w+) bags?."
+    ])
+    .unwrap();
+}
+
+static SEARCHED_BAG: &str = "shiny gold";
+
+type RuleSetData = HashMap<String, Vec<(i32, String)>>;
+type SimpleRuleSetData = HashMap<String, Vec<String>>;
+struct RuleSet {
+    data: RuleSetData,
+}
+
+enum RuleType {
+    Multiple(),
+    Single { key: String, qnt: i32, val: String },
+    Empty(),
+}
+
+impl RuleType {
+    //   light beige bags contain 5 dark green bags, 5 light gray bags, 3 faded indigo bags, 2 vibrant aqua bags.
+    //   vibrant beige bags contain 1 pale silver bag.
+    //   posh yellow bags contain no other bags.
+
+    fn parse_rule(input: &str) -> Result<RuleType> {
+        let matching_regx: Vec<usize> = REGS.matches(input).into_iter().collect();
+        match matching_regx.first() {
+            Some(0) => Ok(RuleType::Multiple()),
+            Some(1) => {
+                let (key, qnt, val) = extract(&RULE_START, input)?;
+                Ok(RuleType::Single { key, qnt, val })
+            }
+            _ if RULE_EMPTY.is_match(input) => Ok(RuleType::Empty()),
+            _ => Err(Error::parse("not a bag rule")),
+        }
+    }
+}
+
+impl RuleSet {
+    fn new() -> RuleSet {
+        RuleSet {
+            data: HashMap::new(),
+        }
+    }
+
+    // Invert to a mapping of "bag type" => "bags that own it"
+    fn invert_ruleset(&self) -> SimpleRuleSetData {
+        let mut res: SimpleRuleSetData = HashMap::new();
+        self.data.iter().for_each(|(key, vals)| {
+            vals.iter().for_each(|(_, val)| {
+                res.entry(val.to_string())
+                    .or_default()
+                    .push(key.to_string())
+            })
+        });
+
+        res
+    }
+
+    fn add_rule(&mut self, input: &str) -> Result<()> {
+        match RuleType::parse_rule(input)? {
+            RuleType::Multiple() => {
+                let mut split_rule = input.split(", ");
+                let first = split_rule.next().unwrap_or_default();
+                let (node, qnt, sub_node) = extract(&RULE_START, first)?;
+                let mut vals: Vec<(i32, String)> = vec![(qnt, sub_node)];
+
+                for txt in split_rule {
+                    vals.push(extract(&RULE_END, txt)?);
+                }
+                self.data.insert(node, vals);
+            }
+            RuleType::Single { key, qnt, val } => {
+                self.data.insert(key, vec![(qnt, val)]);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    // `expanding` holds the bags whose contents are being counted, a bag
+    // that turns up inside itself would never finish
+    fn count_rules<'a>(&'a self, bag: &'a str, expanding: &mut Vec<&'a str>) -> Result<usize> {
+        if expanding.contains(&bag) {
+            return Err(Error::solve(format!(
+                "cyclic rule: {} -> {}",
+                expanding.join(" -> "),
+                bag
+            )));
+        }
+        let vals = match self.data.get(bag) {
+            Some(vals) => vals,
+            None => return Ok(0),
+        };
+        expanding.push(bag);
+        let mut res = 0usize;
+        for (qnt, name) in vals {
+            let qnt = *qnt as usize;
+            res += qnt + qnt * self.count_rules(name, expanding)?;
+        }
+        expanding.pop();
+        Ok(res)
+    }
+}
+
+fn count_containers(input: &SimpleRuleSetData) -> usize {
+    let mut counter = 0usize;
+    let mut visited: HashSet<String> = HashSet::new();
+    let mut key_queue: Queue<String> = queue![SEARCHED_BAG.to_string()];
+
+    while key_queue.size() > 0 {
+        let key = key_queue.remove().ok().unwrap();
+        if let Some(val) = input.get(&key) {
+            val.iter().for_each(|x| {
+                if !visited.contains(x) {
+                    key_queue.add(x.clone()).unwrap();
+                    visited.insert(x.to_string());
+                    counter += 1;
+                }
+            });
+        }
+    }
+
+    counter
+}
+
+pub struct BagRules {
+    ruleset: RuleSet,
+    inverted: SimpleRuleSetData,
+}
+
+pub struct Day07;
+
+impl Solver for Day07 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 7;
+    type Input = BagRules;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, raw_input: &[String]) -> Result<Self::Input> {
+        let mut ruleset = RuleSet::new();
+        parse_lines(raw_input, |x| ruleset.add_rule(x))?;
+        let inverted = ruleset.invert_ruleset();
+        Ok(BagRules { ruleset, inverted })
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Answer1> {
+        Ok(count_containers(&input.inverted))
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Answer2> {
+        input.ruleset.count_rules(SEARCHED_BAG, &mut Vec::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::example_lines;
+
+    static EXAMPLE: &str = "\
+light red bags contain 1 bright white bag, 2 muted yellow bags.
+dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+bright white bags contain 1 shiny gold bag.
+muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+faded blue bags contain no other bags.
+dotted black bags contain no other bags.";
+
+    static NESTED: &str = "\
+shiny gold bags contain 2 dark red bags.
+dark red bags contain 2 dark orange bags.
+dark orange bags contain 2 dark yellow bags.
+dark yellow bags contain 2 dark green bags.
+dark green bags contain 2 dark blue bags.
+dark blue bags contain 2 dark violet bags.
+dark violet bags contain no other bags.";
+
+    #[test]
+    fn part1_example() {
+        let input = Day07.parse(&example_lines(EXAMPLE)).unwrap();
+        assert_eq!(Day07.part1(&input).unwrap(), 4);
+    }
+
+    #[test]
+    fn part2_examples() {
+        let input = Day07.parse(&example_lines(EXAMPLE)).unwrap();
+        assert_eq!(Day07.part2(&input).unwrap(), 32);
+        let input = Day07.parse(&example_lines(NESTED)).unwrap();
+        assert_eq!(Day07.part2(&input).unwrap(), 126);
+    }
+
+    #[test]
+    fn cyclic_rules() {
+        let rules = "\
+shiny gold bags contain 1 dark red bag.
+dark red bags contain 2 dark orange bags, 1 faded blue bag.
+dark orange bags contain 1 shiny gold bag.
+faded blue bags contain no other bags.";
+        let input = Day07.parse(&example_lines(rules)).unwrap();
+        let err = Day07.part2(&input).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("shiny gold -> dark red -> dark orange -> shiny gold"));
+    }
+}
@@ -0,0 +1,70 @@
+use crate::console::{self, Cfg, Instruction, Stop, Vm};
+use crate::error::{Error, Result};
+use crate::solver::Solver;
+
+pub struct Day08;
+
+impl Solver for Day08 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 8;
+    type Input = Vec<Instruction>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    // Puzzle inputs are console assembly without labels, so hand-written
+    // programs with them run as well
+    fn parse(&self, raw_input: &[String]) -> Result<Self::Input> {
+        console::assemble(raw_input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Answer1> {
+        let mut vm = Vm::new(input.to_vec());
+        vm.run()?;
+        Ok(vm.acc())
+    }
+
+    // The control-flow graph finds the one instruction to swap in linear
+    // time, then only the fixed program has to run
+    fn part2(&self, input: &Self::Input) -> Result<Self::Answer2> {
+        let patch = Cfg::new(input)
+            .terminating_patches()
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::solve("no single patch makes the program terminate"))?;
+        let mut vm = Vm::new(input.to_vec());
+        vm.patch(patch.index, patch.instruction)?;
+        match vm.run()? {
+            Stop::Halted => Ok(vm.acc()),
+            _ => Err(Error::solve("the patched program doesn't terminate")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::example_lines;
+
+    static EXAMPLE: &str = "\
+nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6";
+
+    #[test]
+    fn part1_example() {
+        let input = Day08.parse(&example_lines(EXAMPLE)).unwrap();
+        assert_eq!(Day08.part1(&input).unwrap(), 5);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day08.parse(&example_lines(EXAMPLE)).unwrap();
+        assert_eq!(Day08.part2(&input).unwrap(), 8);
+    }
+}
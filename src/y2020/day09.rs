@@ -0,0 +1,133 @@
+use crate::common::{parse_lines, parse_value};
+use crate::error::{Error, Result};
+use crate::solver::Solver;
+use itertools::Itertools;
+use itertools::MinMaxResult::MinMax;
+use std::collections::HashSet;
+
+static PRE_SIZE: usize = 25;
+
+fn find_combination_of2<'a, I>(input_iter: I, target: usize) -> Option<usize>
+where
+    I: Iterator<Item = &'a usize>,
+{
+    let mut complements: HashSet<usize> = HashSet::new();
+    for val in input_iter {
+        let complement = match target.checked_sub(*val) {
+            Some(complement) => complement,
+            None => continue,
+        };
+        if complements.contains(&complement) {
+            return Some(complement * val);
+        }
+        complements.insert(*val);
+    }
+    None
+}
+
+fn find_invalid(input: &[usize], pre_size: usize) -> Option<usize> {
+    let mut preamble = input.iter().copied().take(pre_size).collect::<HashSet<_>>();
+
+    input
+        .iter()
+        .enumerate()
+        .skip(pre_size)
+        .map(|(idx, &curr)| {
+            let comb = find_combination_of2(preamble.iter(), curr);
+            let pre_start = input[idx - pre_size];
+            preamble.insert(curr);
+            preamble.remove(&pre_start);
+            (curr, comb)
+        })
+        .find(|(_, comb)| comb.is_none())
+        .map(|(curr, _)| curr)
+}
+
+fn find_weakness(input: &[usize], invalid: usize) -> Option<usize> {
+    let mut acc: usize = 0;
+    let mut start_idx = 0;
+    for (i, x) in input.iter().enumerate() {
+        if acc > invalid {
+            while acc > invalid {
+                acc -= input[start_idx];
+                start_idx += 1;
+            }
+        }
+
+        if acc == invalid {
+            if let MinMax(mn, mx) = input.iter().skip(start_idx).take(i - start_idx).minmax() {
+                return Some(mx + mn);
+            }
+        }
+
+        acc += x;
+    }
+    None
+}
+
+pub struct Day09;
+
+impl Solver for Day09 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 9;
+    type Input = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, raw_input: &[String]) -> Result<Self::Input> {
+        parse_lines(raw_input, parse_value)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Answer1> {
+        find_invalid(input, PRE_SIZE)
+            .ok_or_else(|| Error::solve("every number is a sum of two preceding ones"))
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Answer2> {
+        let invalid = find_invalid(input, PRE_SIZE)
+            .ok_or_else(|| Error::solve("every number is a sum of two preceding ones"))?;
+        find_weakness(input, invalid)
+            .ok_or_else(|| Error::solve("no contiguous range sums to the invalid number"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::example_lines;
+
+    // The example uses a preamble of 5 instead of 25
+    static EXAMPLE: &str = "\
+35
+20
+15
+25
+47
+40
+62
+55
+65
+95
+102
+117
+150
+182
+127
+219
+299
+277
+309
+576";
+
+    #[test]
+    fn part1_example() {
+        let input = Day09.parse(&example_lines(EXAMPLE)).unwrap();
+        assert_eq!(find_invalid(&input, 5), Some(127));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day09.parse(&example_lines(EXAMPLE)).unwrap();
+        assert_eq!(find_weakness(&input, 127), Some(62));
+    }
+}
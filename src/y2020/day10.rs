@@ -0,0 +1,94 @@
+use crate::common::{parse_lines, parse_value};
+use crate::error::{Error, Result};
+use crate::solver::Solver;
+use std::collections::{HashMap, HashSet};
+
+pub struct Day10;
+
+impl Solver for Day10 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 10;
+    type Input = Vec<i32>;
+    type Answer1 = i32;
+    type Answer2 = usize;
+
+    // Sorted joltages, starting with the charging outlet
+    fn parse(&self, raw_input: &[String]) -> Result<Self::Input> {
+        let mut input: Vec<i32> = parse_lines(raw_input, parse_value)?;
+        input.sort_unstable();
+        input.insert(0, 0);
+        Ok(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Answer1> {
+        let (ones, threes) = input.windows(2).fold((0, 0), |(o, t), window| {
+            let diff = window[1] - window[0];
+            if diff == 1 {
+                return (o + 1, t);
+            } else if diff == 3 {
+                return (o, t + 1);
+            }
+            (o, t)
+        });
+        Ok(ones * (threes + 1))
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Answer2> {
+        let end = input[input.len() - 1] + 3;
+        let sett: HashSet<i32> = input.iter().copied().chain(vec![end]).collect();
+        let mut paths_to_end: HashMap<i32, usize> = HashMap::new();
+        paths_to_end.insert(end, 1);
+
+        // Gaps are at most 3, so once an adapter can't reach the end none
+        // of the smaller ones can either
+        let unchained = || Error::solve("adapters cannot be chained");
+        for &x in input.iter().rev() {
+            for next in [x + 3, x + 2, x + 1].iter().filter(|&x| sett.contains(x)) {
+                let new_val = *paths_to_end.get(next).ok_or_else(unchained)?;
+                paths_to_end
+                    .entry(x)
+                    .and_modify(|x| *x += new_val)
+                    .or_insert(new_val);
+            }
+        }
+
+        paths_to_end
+            .get(&0)
+            .copied()
+            .ok_or_else(unchained)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::example_lines;
+
+    static SMALL: &str = "16\n10\n15\n5\n1\n11\n7\n19\n6\n12\n4";
+
+    static LARGE: &str = "\
+28\n33\n18\n42\n31\n14\n46\n20\n48\n47\n24\n23\n49\n45\n19\n38
+39\n11\n1\n32\n25\n35\n8\n17\n7\n9\n4\n2\n34\n10\n3";
+
+    #[test]
+    fn part1_examples() {
+        let input = Day10.parse(&example_lines(SMALL)).unwrap();
+        assert_eq!(Day10.part1(&input).unwrap(), 35);
+        let input = Day10.parse(&example_lines(LARGE)).unwrap();
+        assert_eq!(Day10.part1(&input).unwrap(), 220);
+    }
+
+    #[test]
+    fn part2_examples() {
+        let input = Day10.parse(&example_lines(SMALL)).unwrap();
+        assert_eq!(Day10.part2(&input).unwrap(), 8);
+        let input = Day10.parse(&example_lines(LARGE)).unwrap();
+        assert_eq!(Day10.part2(&input).unwrap(), 19208);
+    }
+
+    #[test]
+    fn unchained_adapters() {
+        let input = Day10.parse(&example_lines("1\n5")).unwrap();
+        assert!(Day10.part2(&input).is_err());
+    }
+}
@@ -0,0 +1,116 @@
+use crate::automaton::{Automaton, Bounded, Dense, GridTopology, Rule};
+use crate::common::Part;
+use crate::error::{Error, Result};
+use crate::grid::Grid;
+use crate::image::{Frame, Recorder, Rgb};
+use crate::solver::Solver;
+
+// Way more than any seat layout needs to settle
+const MAX_ROUNDS: usize = 1000;
+
+const FLOOR: Rgb = [40, 40, 40];
+const EMPTY: Rgb = [60, 160, 60];
+const OCCUPIED: Rgb = [220, 60, 40];
+
+// Seats are true, floor is false. An occupied seat is a live cell, an empty
+// one comes alive without occupied neighbours and dies with `limit` of them.
+fn seating(seats: &Grid<bool>, part: Part) -> Result<Dense<GridTopology>> {
+    let (topology, limit) = match part {
+        Part::One => (GridTopology::square(seats), 4),
+        Part::Two => (GridTopology::visibility(seats), 5),
+    };
+    let rule = Rule::new(&[0], &(0..limit).collect::<Vec<u32>>())?;
+    Ok(Dense::new(topology, rule))
+}
+
+fn find_equilibrium(seats: &Grid<bool>, part: Part) -> Result<usize> {
+    let mut board = seating(seats, part)?;
+    board
+        .run_to_fixpoint(MAX_ROUNDS)
+        .ok_or_else(|| Error::solve(format!("seats still change after {} rounds", MAX_ROUNDS)))?;
+    Ok(board.population())
+}
+
+// One frame per round, until the seats settle
+pub fn render(seats: &Grid<bool>, part: Part, recorder: &mut Recorder) -> Result<()> {
+    let mut board = seating(seats, part)?;
+    let mut index = Grid::new(seats.width(), seats.height(), None);
+    for cell in 0..board.topology().len() {
+        index[board.topology().position(cell)] = Some(cell);
+    }
+    let frame = |board: &Dense<GridTopology>| {
+        Frame::Pixmap(index.map(|&cell| match cell {
+            None => FLOOR,
+            Some(x) if board.is_alive(x) => OCCUPIED,
+            Some(_) => EMPTY,
+        }))
+    };
+
+    recorder.frame(frame(&board))?;
+    for _ in 0..MAX_ROUNDS {
+        if !board.step() {
+            break;
+        }
+        recorder.frame(frame(&board))?;
+    }
+    Ok(())
+}
+
+pub struct Day11;
+
+impl Solver for Day11 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 11;
+    type Input = Grid<bool>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, raw_input: &[String]) -> Result<Self::Input> {
+        if raw_input.is_empty() {
+            return Err(Error::parse("empty seat layout"));
+        }
+        Grid::parse(raw_input, |c| match c {
+            'L' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Answer1> {
+        find_equilibrium(input, Part::One)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Answer2> {
+        find_equilibrium(input, Part::Two)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::example_lines;
+
+    static EXAMPLE: &str = "\
+L.LL.LL.LL
+LLLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLLL
+L.LLLLLL.L
+L.LLLLL.LL";
+
+    #[test]
+    fn part1_example() {
+        let input = Day11.parse(&example_lines(EXAMPLE)).unwrap();
+        assert_eq!(Day11.part1(&input).unwrap(), 37);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day11.parse(&example_lines(EXAMPLE)).unwrap();
+        assert_eq!(Day11.part2(&input).unwrap(), 26);
+    }
+}
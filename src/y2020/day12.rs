@@ -0,0 +1,215 @@
+use crate::common::{extract, parse_lines};
+use crate::error::{Error, Result};
+use crate::solver::Solver;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::{ops::AddAssign, str::FromStr};
+
+lazy_static! {
+    static ref MOVE_REGX: Regex = Regex::new(r"^(\w)(\d+)$").unwrap();
+}
+
+struct Coords {
+    x: i64,
+    y: i64,
+}
+
+impl AddAssign for Coords {
+    fn add_assign(&mut self, other: Self) {
+        *self = Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        };
+    }
+}
+
+impl Coords {
+    fn new(xx: i64, yy: i64) -> Self {
+        Coords { x: xx, y: yy }
+    }
+
+    fn scale(&self, val: u32) -> Self {
+        let scale = val as i64;
+        Coords {
+            x: self.x * scale,
+            y: self.y * scale,
+        }
+    }
+
+    fn manhattan(&self) -> u32 {
+        (self.x.abs() + self.y.abs()) as u32
+    }
+
+    fn rotate(&self, angle: f64) -> Self {
+        let rads = -(angle * std::f64::consts::PI / 180f64);
+        let sinb = rads.sin();
+        let cosb = rads.cos();
+
+        let nx = cosb * self.x as f64 - sinb * self.y as f64;
+        let ny = sinb * self.x as f64 + cosb * self.y as f64;
+        Coords {
+            x: (nx.round()) as i64,
+            y: (ny.round()) as i64,
+        }
+    }
+}
+
+pub enum Direction {
+    N,
+    E,
+    S,
+    W,
+    R,
+    L,
+    F,
+}
+
+impl FromStr for Direction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "N" => Ok(Direction::N),
+            "E" => Ok(Direction::E),
+            "S" => Ok(Direction::S),
+            "W" => Ok(Direction::W),
+            "R" => Ok(Direction::R),
+            "L" => Ok(Direction::L),
+            "F" => Ok(Direction::F),
+            _ => Err(Error::parse(format!("unknown action `{}`", s))),
+        }
+    }
+}
+
+impl Direction {
+    fn as_coords(&self) -> Coords {
+        match self {
+            Direction::N => Coords { x: 0, y: 1 },
+            Direction::E => Coords { x: 1, y: 0 },
+            Direction::S => Coords { x: 0, y: -1 },
+            Direction::W => Coords { x: -1, y: 0 },
+            _ => Coords { x: 0, y: 0 },
+        }
+    }
+
+    fn as_u8(&self) -> Result<u8> {
+        match self {
+            Direction::N => Ok(0),
+            Direction::E => Ok(1),
+            Direction::S => Ok(2),
+            Direction::W => Ok(3),
+            _ => Err(Error::solve("the ship can only face N, E, S or W")),
+        }
+    }
+
+    fn from_u8(dir: u8) -> Self {
+        match dir & 0x3 {
+            0 => Direction::N,
+            1 => Direction::E,
+            2 => Direction::S,
+            _ => Direction::W,
+        }
+    }
+
+    fn rotate(&self, times: i32) -> Result<Self> {
+        let curr = self.as_u8()?;
+        Ok(Direction::from_u8(curr.wrapping_add((0x40 + times) as u8)))
+    }
+}
+pub struct Move {
+    dir: Direction,
+    val: u32,
+}
+
+impl FromStr for Move {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (dir, val): (Direction, u32) = extract(&MOVE_REGX, s)?;
+        if matches!(dir, Direction::R | Direction::L) && val % 90 != 0 {
+            return Err(Error::parse(format!("cannot turn by {} degrees", val)));
+        }
+        Ok(Move { dir, val })
+    }
+}
+
+pub struct Day12;
+
+impl Solver for Day12 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 12;
+    type Input = Vec<Move>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(&self, raw_input: &[String]) -> Result<Self::Input> {
+        parse_lines(raw_input, str::parse)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Answer1> {
+        let mut ship_pos = Coords::new(0, 0);
+        let mut ship_dir = Direction::E;
+
+        for m in input {
+            match m.dir {
+                Direction::N | Direction::E | Direction::S | Direction::W => {
+                    ship_pos += m.dir.as_coords().scale(m.val)
+                }
+                Direction::R => ship_dir = ship_dir.rotate(m.val as i32 / 90)?,
+                Direction::L => ship_dir = ship_dir.rotate(-(m.val as i32 / 90))?,
+                Direction::F => ship_pos += ship_dir.as_coords().scale(m.val),
+            };
+        }
+
+        Ok(ship_pos.manhattan())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Answer2> {
+        let mut ship_pos = Coords::new(0, 0);
+        let mut ship_dir = Coords::new(10, 1);
+
+        for m in input {
+            match m.dir {
+                Direction::N | Direction::E | Direction::S | Direction::W => {
+                    ship_dir += m.dir.as_coords().scale(m.val)
+                }
+                Direction::R => ship_dir = ship_dir.rotate(m.val as f64),
+                Direction::L => ship_dir = ship_dir.rotate(-(m.val as f64)),
+                Direction::F => ship_pos += ship_dir.scale(m.val),
+            };
+        }
+
+        Ok(ship_pos.manhattan())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::example_lines;
+
+    static EXAMPLE: &str = "\
+F10
+N3
+F7
+R90
+F11";
+
+    #[test]
+    fn part1_example() {
+        let input = Day12.parse(&example_lines(EXAMPLE)).unwrap();
+        assert_eq!(Day12.part1(&input).unwrap(), 25);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day12.parse(&example_lines(EXAMPLE)).unwrap();
+        assert_eq!(Day12.part2(&input).unwrap(), 286);
+    }
+
+    #[test]
+    fn long_turns() {
+        let input = Day12.parse(&example_lines("R17190\nF1")).unwrap();
+        assert_eq!(Day12.part1(&input).unwrap(), 1);
+    }
+}
@@ -0,0 +1,157 @@
+use crate::common::parse_value;
+use crate::error::{Error, Result};
+use crate::solver::Solver;
+
+pub struct TimeTable {
+    time: i64,
+    bus_ids: Vec<(i64, i64)>,
+}
+
+fn modular_multiplicative_inverse(a: i128, m: i128) -> Result<i128> {
+    let (mut r0, mut r1) = (a.rem_euclid(m), m);
+    let (mut s0, mut s1) = (1i128, 0i128);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (s0, s1) = (s1, s0 - q * s1);
+    }
+    if r0 != 1 {
+        return Err(Error::solve("bus ids are not pairwise coprime"));
+    }
+
+    Ok(s0.rem_euclid(m))
+}
+
+fn chinese_remainder(input: &[(i64, i64)]) -> Result<i64> {
+    let n_prod = input
+        .iter()
+        .try_fold(1i64, |acc, &(_, n)| acc.checked_mul(n))
+        .ok_or_else(|| Error::solve("product of bus ids overflows"))?;
+    // With the product in an i64, every intermediate below fits in an i128
+    // and the reduced sum fits back into an i64
+    let n_prod = i128::from(n_prod);
+    let mut sum = 0i128;
+    for &(a, n) in input {
+        let (a, n) = (i128::from(a), i128::from(n));
+        let p = n_prod / n;
+        let mmi = modular_multiplicative_inverse(p, n)?;
+        sum = (sum + (n - a).rem_euclid(n) * mmi % n * p) % n_prod;
+    }
+    Ok(sum as i64)
+}
+
+pub struct Day13;
+
+impl Solver for Day13 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 13;
+    type Input = TimeTable;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(&self, raw_input: &[String]) -> Result<Self::Input> {
+        let (time_line, bus_line) = match raw_input {
+            [time_line, bus_line, ..] => (time_line, bus_line),
+            _ => return Err(Error::parse("expected a timestamp and a bus list")),
+        };
+        let time = parse_value(time_line).map_err(|e| e.at_line(1, time_line))?;
+        let bus_ids = bus_line
+            .split(',')
+            .enumerate()
+            .filter(|&(_, x)| x != "x")
+            .map(|(x, y)| match parse_value(y)? {
+                id if id < 1 => Err(Error::parse("bus id must be positive")),
+                id => Ok((x as i64, id)),
+            })
+            .collect::<Result<Vec<_>>>()
+            .map_err(|e| e.at_line(2, bus_line))?;
+        if bus_ids.is_empty() {
+            return Err(Error::parse("no buses in service").at_line(2, bus_line));
+        }
+        Ok(TimeTable { time, bus_ids })
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Answer1> {
+        let (bus_id, wait_time) = input
+            .bus_ids
+            .iter()
+            .map(|(_, x)| x)
+            .map(|&x| (x, x - (input.time % x)))
+            .min_by(|(_, x), (_, y)| x.cmp(y))
+            .ok_or_else(|| Error::solve("no buses in service"))?;
+        Ok(bus_id * wait_time)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Answer2> {
+        chinese_remainder(&input.bus_ids)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::example_lines;
+
+    static EXAMPLE: &str = "\
+939
+7,13,x,x,59,x,31,19";
+
+    #[test]
+    fn part1_example() {
+        let input = Day13.parse(&example_lines(EXAMPLE)).unwrap();
+        assert_eq!(Day13.part1(&input).unwrap(), 295);
+    }
+
+    #[test]
+    fn part2_examples() {
+        let input = Day13.parse(&example_lines(EXAMPLE)).unwrap();
+        assert_eq!(Day13.part2(&input).unwrap(), 1068781);
+
+        // Only the bus list matters for part 2
+        for (buses, expected) in [
+            ("17,x,13,19", 3417),
+            ("67,7,59,61", 754018),
+            ("67,x,7,59,61", 779210),
+            ("67,7,x,59,61", 1261476),
+            ("1789,37,47,1889", 1202161486),
+        ] {
+            let input = Day13
+                .parse(&example_lines(&format!("939\n{}", buses)))
+                .unwrap();
+            assert_eq!(Day13.part2(&input).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn rejects_bus_ids_below_one() {
+        for buses in ["7,0,13", "7,-13"] {
+            let err = Day13
+                .parse(&example_lines(&format!("939\n{}", buses)))
+                .err()
+                .unwrap();
+            assert_eq!(err.line(), Some(2));
+        }
+    }
+
+    #[test]
+    fn part2_rejects_shared_factors() {
+        let input = Day13.parse(&example_lines("939\n6,x,4")).unwrap();
+        assert!(Day13.part2(&input).is_err());
+    }
+
+    #[test]
+    fn part2_rejects_overflowing_products() {
+        let buses = ["1000003"; 4].join(",");
+        let input = Day13
+            .parse(&example_lines(&format!("939\n{}", buses)))
+            .unwrap();
+        assert!(Day13.part2(&input).is_err());
+    }
+
+    #[test]
+    fn part2_offsets_beyond_the_bus_id() {
+        // Bus 2 departs 3 minutes after t, i.e. t is odd
+        let input = Day13.parse(&example_lines("939\n3,x,x,2")).unwrap();
+        assert_eq!(Day13.part2(&input).unwrap(), 3);
+    }
+}
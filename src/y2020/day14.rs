@@ -0,0 +1,175 @@
+use crate::common::{extract, extract_one};
+use crate::error::{Error, Result};
+use crate::solver::Solver;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashMap;
+
+lazy_static! {
+    static ref MASK_REGX: Regex = Regex::new(r"^mask = ([X01]{36})$").unwrap();
+    static ref MEM_REGX: Regex = Regex::new(r"^mem\[(\d+)\] = (\d+)$").unwrap();
+}
+
+pub struct BitmaskProg {
+    masked_bits: Vec<(usize, u8)>,
+    mem_inputs: Vec<(usize, u64)>,
+
+    floating_bits: Vec<usize>,
+    ones_mask: u64,
+}
+
+impl BitmaskProg {
+    fn apply_mask(&self, val: u64) -> u64 {
+        let mut toggle_mask = 0u64;
+        for (i, x) in &self.masked_bits {
+            if *x != BitmaskProg::check_bit(val, *i) {
+                toggle_mask |= 0x1 << *i;
+            }
+        }
+
+        val ^ toggle_mask
+    }
+
+    fn apply_ones(&self, val: u64) -> u64 {
+        val | self.ones_mask
+    }
+
+    fn generate_floatings(&self, val: u64) -> Vec<u64> {
+        let mut res = vec![val];
+        for ff in &self.floating_bits {
+            let mut new_v: Vec<u64> = Vec::with_capacity(res.len() * self.floating_bits.len());
+            for rr in res {
+                new_v.push(rr);
+                new_v.push(rr ^ (0x1 << *ff));
+            }
+            res = new_v;
+        }
+        res
+    }
+
+    fn check_bit(x: u64, i: usize) -> u8 {
+        ((x >> i) & 0x1) as u8
+    }
+}
+
+pub struct Day14;
+
+impl Solver for Day14 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 14;
+    type Input = Vec<BitmaskProg>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(&self, raw_input: &[String]) -> Result<Self::Input> {
+        let mut programes: Vec<BitmaskProg> = Vec::new();
+        let mut masked_bits: Vec<(usize, u8)> = Vec::new();
+        let mut mem_inputs: Vec<(usize, u64)> = Vec::new();
+        let mut floating_bits: Vec<usize> = Vec::new();
+        let mut ones_mask = 0u64;
+
+        for (idx, line) in raw_input.iter().enumerate() {
+            let line_err = |e: Error| e.at_line(idx + 1, line);
+            if line.starts_with("mask") {
+                if !mem_inputs.is_empty() {
+                    programes.push(BitmaskProg {
+                        masked_bits,
+                        mem_inputs,
+                        floating_bits,
+                        ones_mask,
+                    });
+
+                    mem_inputs = Vec::new();
+                }
+                let mask_line: String = extract_one(&MASK_REGX, line).map_err(line_err)?;
+                masked_bits = mask_line
+                    .as_bytes()
+                    .iter()
+                    .rev()
+                    .enumerate()
+                    .filter(|&(_, &x)| x != b'X')
+                    .map(|(i, &x)| (i, x - b'0'))
+                    .collect();
+                ones_mask = masked_bits
+                    .iter()
+                    .fold(0u64, |mask, &(i, x)| mask | ((x as u64) << i));
+                floating_bits = mask_line
+                    .as_bytes()
+                    .iter()
+                    .rev()
+                    .enumerate()
+                    .filter(|&(_, &x)| x == b'X')
+                    .map(|(i, _)| i)
+                    .collect();
+            } else {
+                mem_inputs.push(extract(&MEM_REGX, line).map_err(line_err)?);
+            }
+        }
+        programes.push(BitmaskProg {
+            masked_bits,
+            mem_inputs,
+            floating_bits,
+            ones_mask,
+        });
+
+        Ok(programes)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Answer1> {
+        let mut mem: HashMap<usize, u64> = HashMap::new();
+        for prog in input {
+            prog.mem_inputs.iter().for_each(|&(i, x)| {
+                let changed = prog.apply_mask(x);
+                mem.insert(i, changed);
+            });
+        }
+
+        Ok(mem.values().sum::<u64>())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Answer2> {
+        let mut mem: HashMap<usize, u64> = HashMap::new();
+        for prog in input {
+            prog.mem_inputs.iter().for_each(|&(i, x)| {
+                prog.generate_floatings(prog.apply_ones(i as u64))
+                    .iter()
+                    .for_each(|&ii| {
+                        mem.insert(ii as usize, x);
+                    });
+            });
+        }
+
+        Ok(mem.values().sum::<u64>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::example_lines;
+
+    static EXAMPLE: &str = "\
+mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X
+mem[8] = 11
+mem[7] = 101
+mem[8] = 0";
+
+    // Part 2 has its own example, the first one would float into 2^34 addresses
+    static FLOATING: &str = "\
+mask = 000000000000000000000000000000X1001X
+mem[42] = 100
+mask = 00000000000000000000000000000000X0XX
+mem[26] = 1";
+
+    #[test]
+    fn part1_example() {
+        let input = Day14.parse(&example_lines(EXAMPLE)).unwrap();
+        assert_eq!(Day14.part1(&input).unwrap(), 165);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day14.parse(&example_lines(FLOATING)).unwrap();
+        assert_eq!(Day14.part2(&input).unwrap(), 208);
+    }
+}
@@ -0,0 +1,89 @@
+use crate::common::{parse_lines, parse_value};
+use crate::error::{Error, Result};
+use crate::solver::Solver;
+use std::collections::HashMap;
+
+fn find_nth_van_eck(input: &[u32], limit: usize) -> Result<u32> {
+    let mut last_num = *input
+        .last()
+        .ok_or_else(|| Error::solve("no starting numbers"))?;
+    //for some reason, creating ::with_capacity() even with correct capacity makes this solution 2x slower...no idea why .__.
+    let mut num_to_age: HashMap<u32, usize> = HashMap::new();
+    let mut counter = input.len();
+    for (i, &x) in input.iter().take(counter - 1).enumerate() {
+        num_to_age.insert(x, i + 1);
+    }
+    while counter < limit {
+        if let Some(&last) = num_to_age.get(&last_num) {
+            let new_num = counter - last;
+            num_to_age.insert(last_num, counter);
+            last_num = new_num as u32;
+        } else {
+            num_to_age.insert(last_num, counter);
+            last_num = 0;
+        }
+        counter += 1;
+    }
+
+    Ok(last_num)
+}
+
+pub struct Day15;
+
+impl Solver for Day15 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 15;
+    type Input = Vec<u32>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(&self, raw_input: &[String]) -> Result<Self::Input> {
+        let input: Vec<u32> = parse_lines(raw_input, |x| {
+            x.split(',').map(parse_value).collect::<Result<Vec<u32>>>()
+        })?
+        .into_iter()
+        .flatten()
+        .collect();
+        if input.is_empty() {
+            return Err(Error::parse("no starting numbers"));
+        }
+        Ok(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Answer1> {
+        find_nth_van_eck(input, 2020)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Answer2> {
+        find_nth_van_eck(input, 30_000_000)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::example_lines;
+
+    #[test]
+    fn part1_examples() {
+        for (start, expected) in [
+            ("0,3,6", 436),
+            ("1,3,2", 1),
+            ("2,1,3", 10),
+            ("1,2,3", 27),
+            ("2,3,1", 78),
+            ("3,2,1", 438),
+            ("3,1,2", 1836),
+        ] {
+            let input = Day15.parse(&example_lines(start)).unwrap();
+            assert_eq!(Day15.part1(&input).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    #[ignore = "30 million turns take a while without optimizations"]
+    fn part2_example() {
+        let input = Day15.parse(&example_lines("0,3,6")).unwrap();
+        assert_eq!(Day15.part2(&input).unwrap(), 175594);
+    }
+}
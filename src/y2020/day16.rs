@@ -0,0 +1,292 @@
+use crate::common::{extract, parse_value, split_sections, Section};
+use crate::error::{Error, Result};
+use crate::solver::Solver;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::{collections::HashMap, fmt};
+
+lazy_static! {
+    static ref TICKET_FIELD: Regex =
+        Regex::new(r"^([a-z ]+): (\d+)-(\d+) or (\d+)-(\d+)$").unwrap();
+}
+
+pub type Ticket = Vec<u32>;
+pub type TicketFields = HashMap<String, Ranges>;
+
+// Valid values of a field, `from1-to1 or from2-to2`
+#[derive(Copy, Clone)]
+pub struct Ranges {
+    pub from1: u32,
+    pub to1: u32,
+    pub from2: u32,
+    pub to2: u32,
+}
+
+impl fmt::Display for Ranges {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "({}-{} || {}-{})",
+            self.from1, self.to1, self.from2, self.to2
+        )
+    }
+}
+
+pub struct TicketData {
+    my_ticket: Ticket,
+    tickets: Vec<Ticket>,
+    fields: TicketFields,
+}
+
+impl TicketData {
+    // Nearby tickets must have as many values as yours
+    pub fn new(my_ticket: Ticket, tickets: Vec<Ticket>, fields: TicketFields) -> Result<Self> {
+        if let Some(idx) = tickets.iter().position(|x| x.len() != my_ticket.len()) {
+            return Err(Error::parse(format!(
+                "nearby ticket {} has {} values instead of {}",
+                idx + 1,
+                tickets[idx].len(),
+                my_ticket.len()
+            )));
+        }
+        Ok(TicketData {
+            my_ticket,
+            tickets,
+            fields,
+        })
+    }
+
+    pub fn my_ticket(&self) -> &Ticket {
+        &self.my_ticket
+    }
+
+    pub fn tickets(&self) -> &[Ticket] {
+        &self.tickets
+    }
+
+    pub fn fields(&self) -> &TicketFields {
+        &self.fields
+    }
+}
+
+fn check_range(x: u32, range: Ranges) -> bool {
+    (x >= range.from1 && x <= range.to1) || (x >= range.from2 && x <= range.to2)
+}
+
+// Drains field_counter! Returns None when the fields cannot be told apart
+fn resolve_into_field_mapping(
+    field_counter: &mut HashMap<String, Vec<bool>>,
+) -> Option<HashMap<String, usize>> {
+    let mut field_mapping: HashMap<String, usize> = HashMap::new();
+    while !field_counter.is_empty() {
+        let good_fields: Vec<(String, usize)> = field_counter
+            .iter()
+            .filter_map(|(x, y)| {
+                let goods: Vec<(usize, bool)> = y
+                    .iter()
+                    .enumerate()
+                    .map(|(i, &vv)| (i, vv))
+                    .filter(|(_, vv)| *vv)
+                    .collect();
+                if goods.len() == 1 {
+                    Some((x.to_owned(), goods[0].0))
+                } else {
+                    None
+                }
+            })
+            .collect();
+        if good_fields.is_empty() {
+            return None;
+        }
+        good_fields.iter().for_each(|(k, v)| {
+            let field = field_mapping.entry(k.to_owned()).or_default();
+            *field = *v;
+            field_counter.remove(k);
+            field_counter.iter_mut().for_each(|(_, x)| x[*v] = false);
+        });
+    }
+
+    Some(field_mapping)
+}
+
+fn parse_ticket(line: &str) -> Result<Ticket> {
+    line.split(',').map(parse_value).collect()
+}
+
+fn ticket_section(section: &Section, title: &str) -> Result<Vec<Ticket>> {
+    match section.split_header() {
+        Some((header, tickets)) if header == title => tickets.parse_lines(parse_ticket),
+        _ => Err(Error::parse(format!("expected `{}`", title))
+            .at_line(section.first_line, &section.lines[0])),
+    }
+}
+
+pub struct Day16;
+
+impl Solver for Day16 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 16;
+    type Input = TicketData;
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    fn parse(&self, raw_input: &[String]) -> Result<Self::Input> {
+        let (fields, mine, nearby) = match split_sections(raw_input)[..] {
+            [fields, mine, nearby] => (fields, mine, nearby),
+            _ => {
+                return Err(Error::parse(
+                    "expected sections of fields, your ticket and nearby tickets",
+                ))
+            }
+        };
+
+        let fields = fields.parse_lines(|line| {
+            let (name, from1, to1, from2, to2) = extract(&TICKET_FIELD, line)?;
+            let ranges = Ranges {
+                from1,
+                to1,
+                from2,
+                to2,
+            };
+            Ok((name, ranges))
+        })?;
+
+        let my_ticket = match &ticket_section(&mine, "your ticket:")?[..] {
+            [ticket] => ticket.clone(),
+            _ => return Err(Error::parse("expected exactly one ticket of yours")),
+        };
+        let tickets = ticket_section(&nearby, "nearby tickets:")?;
+        let bad_ticket = tickets.iter().position(|x| x.len() != my_ticket.len());
+        if let Some(idx) = bad_ticket {
+            let msg = format!("expected {} values", my_ticket.len());
+            let line = &nearby.lines[idx + 1];
+            return Err(Error::parse(msg).at_line(nearby.first_line + idx + 1, line));
+        }
+
+        TicketData::new(my_ticket, tickets, fields.into_iter().collect())
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Answer1> {
+        Ok(input
+            .tickets
+            .iter()
+            .flat_map(|x| {
+                x.iter()
+                    .filter_map(|&y| {
+                        if !input.fields.iter().any(|(_, &range)| check_range(y, range)) {
+                            return Some(y);
+                        }
+                        None
+                    })
+                    .collect::<Vec<u32>>()
+            })
+            .sum::<u32>())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Answer2> {
+        let field_mapping = map_fields(input)?;
+
+        Ok(input
+            .fields
+            .keys()
+            .filter(|&x| x.starts_with("departure"))
+            .map(|x| input.my_ticket[field_mapping[x]] as u64)
+            .product::<u64>())
+    }
+}
+
+// Finds the ticket column of every field, using only the valid nearby tickets
+fn map_fields(input: &TicketData) -> Result<HashMap<String, usize>> {
+    let mut counter: HashMap<String, Vec<bool>> = input
+        .fields
+        .keys()
+        .map(|k| (k.to_owned(), vec![true; input.my_ticket.len()]))
+        .collect();
+
+    input
+        .tickets
+        .iter()
+        .filter(|&x| {
+            let res = x
+                .iter()
+                .all(|&y| input.fields.iter().any(|(_, &range)| check_range(y, range)));
+            res
+        })
+        .for_each(|ticket_vals| {
+            ticket_vals.iter().enumerate().for_each(|(idx, &y)| {
+                for (name, range) in input.fields.iter() {
+                    if !check_range(y, *range) {
+                        let ctr = counter.entry(name.to_owned()).or_default();
+                        ctr[idx] = false;
+                    }
+                }
+            })
+        });
+
+    resolve_into_field_mapping(&mut counter)
+        .ok_or_else(|| Error::solve("ticket fields cannot be told apart"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::example_lines;
+
+    static EXAMPLE: &str = "\
+class: 1-3 or 5-7
+row: 6-11 or 33-44
+seat: 13-40 or 45-50
+
+your ticket:
+7,1,14
+
+nearby tickets:
+7,3,47
+40,4,50
+55,2,20
+38,6,12";
+
+    static FIELDS: &str = "\
+class: 0-1 or 4-19
+row: 0-5 or 8-19
+seat: 0-13 or 16-19
+
+your ticket:
+11,12,13
+
+nearby tickets:
+3,9,18
+15,1,5
+5,14,9";
+
+    #[test]
+    fn part1_example() {
+        let input = Day16.parse(&example_lines(EXAMPLE)).unwrap();
+        assert_eq!(Day16.part1(&input).unwrap(), 71);
+    }
+
+    // The example has no departure fields, so only the mapping itself can be checked
+    #[test]
+    fn part2_example() {
+        let input = Day16.parse(&example_lines(FIELDS)).unwrap();
+        let mapping = map_fields(&input).unwrap();
+        let my_value = |field: &str| input.my_ticket[mapping[field]];
+        assert_eq!(my_value("class"), 12);
+        assert_eq!(my_value("row"), 11);
+        assert_eq!(my_value("seat"), 13);
+        assert_eq!(Day16.part2(&input).unwrap(), 1);
+    }
+
+    #[test]
+    fn ticket_lengths() {
+        let fields: TicketFields = hashmap!("class".to_string() => Ranges {
+            from1: 1,
+            to1: 3,
+            from2: 5,
+            to2: 7,
+        });
+        let input = TicketData::new(vec![7], vec![vec![3], vec![4]], fields.clone()).unwrap();
+        assert_eq!(Day16.part1(&input).unwrap(), 4);
+        assert!(TicketData::new(vec![7], vec![vec![3, 4]], fields).is_err());
+    }
+}
@@ -0,0 +1,135 @@
+use crate::automaton::{Automaton, Lattice, Rule, Sparse};
+use crate::common::{parse_lines, Part};
+use crate::error::{Error, Result};
+use crate::grid::Grid;
+use crate::image::{Frame, Recorder};
+use crate::points::{Bounds, Point2, PointN};
+use crate::solver::Solver;
+
+const CYCLES: usize = 6;
+
+fn pocket<const N: usize>(input: &InputType) -> Result<Sparse<Lattice<N>>> {
+    let rule = Rule::new(&[3], &[2, 3])?;
+    Sparse::new(Lattice::<N>, rule, input.iter().copied().map(PointN::embed))
+}
+
+fn infinite_conway<const N: usize>(input: &InputType, cycles: usize) -> Result<usize> {
+    let mut cubes = pocket::<N>(input)?;
+    cubes.run(cycles);
+    Ok(cubes.population())
+}
+
+// The slice through the starting plane after every cycle. It grows by at
+// most one cube per cycle, so all frames can have the same size.
+fn render_slice<const N: usize>(input: &InputType, recorder: &mut Recorder) -> Result<()> {
+    let bounds = Bounds::of(input).ok_or_else(|| Error::solve("no active cubes"))?;
+    let margin = CYCLES as i32;
+    let size = bounds.size();
+    let (width, height) = (size[0] + 2 * margin, size[1] + 2 * margin);
+
+    let mut cubes = pocket::<N>(input)?;
+    for cycle in 0..=CYCLES {
+        if cycle > 0 {
+            cubes.step();
+        }
+        let slice = Grid::from_fn(width as usize, height as usize, |(x, y)| {
+            let x = bounds.min[0] - margin + x as i32;
+            let y = bounds.min[1] - margin + y as i32;
+            cubes.is_alive(&PointN::embed(Point2::new([x, y])))
+        });
+        recorder.frame(Frame::Bitmap(slice))?;
+    }
+    Ok(())
+}
+
+pub fn render(input: &InputType, part: Part, recorder: &mut Recorder) -> Result<()> {
+    match part {
+        Part::One => render_slice::<3>(input, recorder),
+        Part::Two => render_slice::<4>(input, recorder),
+    }
+}
+
+// The same simulation with the input slice placed in 2 to 6 dimensions
+pub fn active_cubes(input: &InputType, dimensions: usize, cycles: usize) -> Result<usize> {
+    match dimensions {
+        2 => infinite_conway::<2>(input, cycles),
+        3 => infinite_conway::<3>(input, cycles),
+        4 => infinite_conway::<4>(input, cycles),
+        5 => infinite_conway::<5>(input, cycles),
+        6 => infinite_conway::<6>(input, cycles),
+        _ => Err(Error::solve(format!(
+            "{} dimensions are not supported, only 2 to 6",
+            dimensions
+        ))),
+    }
+}
+
+pub type InputType = Vec<Point2>;
+
+pub struct Day17;
+
+impl Solver for Day17 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 17;
+    type Input = InputType;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, raw_input: &[String]) -> Result<Self::Input> {
+        let mut ridx = 0;
+        let rows = parse_lines(raw_input, |row| {
+            let points = row
+                .chars()
+                .enumerate()
+                .filter_map(|(cidx, value)| match value {
+                    '#' => Some(Ok(Point2::new([cidx as i32, ridx]))),
+                    '.' => None,
+                    _ => Some(Err(Error::parse(format!("unexpected cube `{}`", value)))),
+                })
+                .collect::<Result<Vec<Point2>>>();
+            ridx += 1;
+            points
+        })?;
+        Ok(rows.into_iter().flatten().collect())
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Answer1> {
+        active_cubes(input, 3, CYCLES)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Answer2> {
+        active_cubes(input, 4, CYCLES)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::example_lines;
+
+    static EXAMPLE: &str = "\
+.#.
+..#
+###";
+
+    #[test]
+    fn part1_example() {
+        let input = Day17.parse(&example_lines(EXAMPLE)).unwrap();
+        assert_eq!(Day17.part1(&input).unwrap(), 112);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day17.parse(&example_lines(EXAMPLE)).unwrap();
+        assert_eq!(Day17.part2(&input).unwrap(), 848);
+    }
+
+    #[test]
+    fn other_dimensions() {
+        let input = Day17.parse(&example_lines(EXAMPLE)).unwrap();
+        // A glider, still 5 cells after any number of generations
+        assert_eq!(active_cubes(&input, 2, 6).unwrap(), 5);
+        assert_eq!(active_cubes(&input, 5, 6).unwrap(), 5760);
+        assert!(active_cubes(&input, 7, 6).is_err());
+    }
+}
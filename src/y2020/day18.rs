@@ -0,0 +1,235 @@
+use crate::common::{parse_lines, parse_value};
+use crate::error::{Error, Result};
+use crate::solver::Solver;
+use std::{collections::HashMap, str::FromStr};
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Ops {
+    Add,
+    Mul,
+}
+
+impl Ops {
+    fn eval(&self, a: u64, b: u64) -> Result<u64> {
+        match self {
+            Ops::Add => a.checked_add(b),
+            Ops::Mul => a.checked_mul(b),
+        }
+        .ok_or_else(|| Error::solve("expression value overflows"))
+    }
+}
+
+impl FromStr for Ops {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "+" => Ok(Ops::Add),
+            "*" => Ok(Ops::Mul),
+            _ => Err(Error::parse(format!("unknown operator `{}`", s))),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Token {
+    Value(u64),
+    Op(Ops),
+    LParens,
+    RParens,
+}
+
+impl FromStr for Token {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "(" => Ok(Token::LParens),
+            ")" => Ok(Token::RParens),
+            op @ "*" | op @ "+" => Ok(Token::Op(op.parse()?)),
+            c => Ok(Token::Value(parse_value(c)?)),
+        }
+    }
+}
+
+// Operands and operators have to alternate and parens have to match,
+// so that evaluating the expression later on cannot fail
+fn check_syntax(line: &[Token]) -> Result<()> {
+    let mut depth = 0usize;
+    let mut expects_operand = true;
+    for tk in line {
+        match (tk, expects_operand) {
+            (Token::Value(_), true) => expects_operand = false,
+            (Token::LParens, true) => depth += 1,
+            (Token::Op(_), false) => expects_operand = true,
+            (Token::RParens, false) if depth > 0 => depth -= 1,
+            (Token::RParens, false) => return Err(Error::parse("unmatched `)`")),
+            _ => return Err(Error::parse("malformed expression")),
+        }
+    }
+
+    if expects_operand {
+        Err(Error::parse("incomplete expression"))
+    } else if depth > 0 {
+        Err(Error::parse("unmatched `(`"))
+    } else {
+        Ok(())
+    }
+}
+
+// Shunting-Yard
+fn eval_onp(stack: Vec<Token>) -> Result<u64> {
+    let malformed = || Error::solve("malformed expression");
+    let mut results: Vec<u64> = Vec::new();
+
+    for t in stack {
+        match t {
+            Token::Op(op) => {
+                let a = results.pop().ok_or_else(malformed)?;
+                let b = results.pop().ok_or_else(malformed)?;
+                results.push(op.eval(a, b)?);
+            }
+            Token::Value(val) => results.push(val),
+            _ => {}
+        }
+    }
+
+    match results[..] {
+        [res] => Ok(res),
+        _ => Err(malformed()),
+    }
+}
+
+fn build_onp(line: &Vec<Token>, priorities: &HashMap<Token, u8>) -> Vec<Token> {
+    let mut stack: Vec<Token> = Vec::with_capacity(line.len());
+    let mut output: Vec<Token> = Vec::with_capacity(line.len());
+    for &c in line {
+        match c {
+            tk @ Token::Value(_) => output.push(tk),
+            tk @ Token::LParens => stack.push(tk),
+            Token::RParens => {
+                while let Some(top) = stack.pop() {
+                    if top == Token::LParens {
+                        break;
+                    }
+                    output.push(top);
+                }
+            }
+            tk @ Token::Op(_) => {
+                let prio = priorities.get(&tk);
+                while let Some(&top) = stack.last() {
+                    if priorities.get(&top) < prio {
+                        break;
+                    }
+                    stack.pop();
+                    output.push(top);
+                }
+                stack.push(tk);
+            }
+        }
+    }
+
+    stack.drain(..).rev().for_each(|tk| output.push(tk));
+    output
+}
+
+fn sum_expressions(input: &[Vec<Token>], priorities: &HashMap<Token, u8>) -> Result<u64> {
+    input.iter().try_fold(0u64, |acc, x| {
+        let val = eval_onp(build_onp(x, priorities))?;
+        acc.checked_add(val)
+            .ok_or_else(|| Error::solve("sum of the expressions overflows"))
+    })
+}
+
+pub struct Day18;
+
+impl Solver for Day18 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 18;
+    type Input = Vec<Vec<Token>>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(&self, raw_input: &[String]) -> Result<Self::Input> {
+        parse_lines(raw_input, |x| {
+            let tokens = x
+                .chars()
+                .filter(|x| x != &' ')
+                .map(|x| x.to_string().parse())
+                .collect::<Result<Vec<Token>>>()?;
+            check_syntax(&tokens)?;
+            Ok(tokens)
+        })
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Answer1> {
+        let priority_map: HashMap<Token, u8> = hashmap!(
+            Token::LParens => 0,
+            Token::RParens => 1,
+            Token::Op(Ops::Mul) => 1,
+            Token::Op(Ops::Add) => 1
+        );
+
+        sum_expressions(input, &priority_map)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Answer2> {
+        let priority_map: HashMap<Token, u8> = hashmap!(
+            Token::LParens => 0,
+            Token::RParens => 1,
+            Token::Op(Ops::Mul) => 1,
+            Token::Op(Ops::Add) => 2
+        );
+
+        sum_expressions(input, &priority_map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::example_lines;
+
+    // (expression, part 1 result, part 2 result)
+    static EXAMPLES: [(&str, u64, u64); 6] = [
+        ("1 + 2 * 3 + 4 * 5 + 6", 71, 231),
+        ("1 + (2 * 3) + (4 * (5 + 6))", 51, 51),
+        ("2 * 3 + (4 * 5)", 26, 46),
+        ("5 + (8 * 3 + 9 + 3 * 4 * 3)", 437, 1445),
+        (
+            "5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))",
+            12240,
+            669060,
+        ),
+        (
+            "((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2",
+            13632,
+            23340,
+        ),
+    ];
+
+    #[test]
+    fn part1_examples() {
+        for (expr, expected, _) in EXAMPLES {
+            let input = Day18.parse(&example_lines(expr)).unwrap();
+            assert_eq!(Day18.part1(&input).unwrap(), expected, "{}", expr);
+        }
+    }
+
+    #[test]
+    fn part2_examples() {
+        for (expr, _, expected) in EXAMPLES {
+            let input = Day18.parse(&example_lines(expr)).unwrap();
+            assert_eq!(Day18.part2(&input).unwrap(), expected, "{}", expr);
+        }
+    }
+
+    #[test]
+    fn malformed_expressions() {
+        assert!(Day18.parse(&example_lines("1 + * 2")).is_err());
+        assert!(Day18.part1(&vec![vec![Token::Op(Ops::Add)]]).is_err());
+        assert!(Day18.part1(&vec![vec![Token::Value(1), Token::Value(2)]]).is_err());
+        let input = Day18.parse(&example_lines(&vec!["9"; 21].join(" * "))).unwrap();
+        assert!(Day18.part1(&input).is_err());
+    }
+}
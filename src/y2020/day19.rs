@@ -0,0 +1,291 @@
+use crate::common::{parse_value, split_sections};
+use crate::error::{Error, Result};
+use crate::solver::Solver;
+use regex::Regex;
+use std::{collections::HashMap, str::FromStr};
+
+// A rule matches either one sequence or one of two
+#[derive(Debug, Clone)]
+pub enum RuleOp {
+    Concat(Rule),
+    Or(Rule, Rule),
+}
+// Literal text, or the numbers of the rules to match one after another
+#[derive(Debug, Clone)]
+pub enum Rule {
+    Value(String),
+    Pointers(Vec<u8>),
+}
+
+impl FromStr for Rule {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let ss = s
+            .split_whitespace()
+            .map(parse_value)
+            .collect::<Result<Vec<u8>>>()?;
+        Ok(Rule::Pointers(ss))
+    }
+}
+
+fn parse_rule_line(line: &str) -> Result<(u8, RuleOp)> {
+    let (idx, rest) = line
+        .split_once(':')
+        .ok_or_else(|| Error::parse("missing `:` after the rule number"))?;
+    let idx = parse_value(idx)?;
+
+    let rule = if rest.contains('"') {
+        let c = rest.trim().trim_matches('"');
+        if c.is_empty() {
+            return Err(Error::parse("empty character rule"));
+        }
+        RuleOp::Concat(Rule::Value(c.to_string()))
+    } else if let Some((or1, or2)) = rest.split_once('|') {
+        RuleOp::Or(or1.parse()?, or2.parse()?)
+    } else {
+        RuleOp::Concat(rest.parse()?)
+    };
+    Ok((idx, rule))
+}
+
+#[derive(Clone)]
+pub struct MsgDecoder {
+    ruleset: HashMap<u8, RuleOp>,
+    msgs: Vec<String>,
+}
+
+impl MsgDecoder {
+    pub fn new(ruleset: HashMap<u8, RuleOp>, msgs: Vec<String>) -> Self {
+        MsgDecoder { ruleset, msgs }
+    }
+
+    pub fn rules(&self) -> &HashMap<u8, RuleOp> {
+        &self.ruleset
+    }
+
+    pub fn messages(&self) -> &[String] {
+        &self.msgs
+    }
+
+    fn concat_rules(&self, x: &Rule, expanding: &mut Vec<u8>) -> Result<String> {
+        match x {
+            Rule::Value(c) => Ok(regex::escape(c)),
+            Rule::Pointers(ptrs) => {
+                let mut res = String::new();
+                for p in ptrs {
+                    res.push_str(self.get_regex(*p, expanding)?.as_str());
+                }
+                Ok(res)
+            }
+        }
+    }
+
+    // `expanding` holds the rules being turned into a regex, one that refers
+    // back to itself would never finish
+    fn get_regex(&self, rule: u8, expanding: &mut Vec<u8>) -> Result<String> {
+        if expanding.contains(&rule) {
+            let path: Vec<String> = expanding.iter().map(|x| x.to_string()).collect();
+            return Err(Error::solve(format!(
+                "cyclic rule: {} -> {}",
+                path.join(" -> "),
+                rule
+            )));
+        }
+        expanding.push(rule);
+        let res = match self.ruleset.get(&rule) {
+            Some(RuleOp::Concat(x)) => self.concat_rules(x, expanding)?,
+            Some(RuleOp::Or(x, y)) => format!(
+                "(?:{}|{})",
+                self.concat_rules(x, expanding)?,
+                self.concat_rules(y, expanding)?
+            ),
+            None => return Err(Error::solve(format!("rule {} is not defined", rule))),
+        };
+        expanding.pop();
+        Ok(res)
+    }
+
+    fn count_matching(&self) -> Result<usize> {
+        let regstr = format!("^{}$", self.get_regex(0, &mut Vec::new())?);
+        let reg = Regex::new(regstr.as_str()).map_err(|e| Error::solve(e.to_string()))?;
+
+        Ok(self.msgs.iter().filter(|&x| reg.is_match(x)).count())
+    }
+}
+
+pub struct Day19;
+
+impl Solver for Day19 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 19;
+    type Input = MsgDecoder;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, raw_input: &[String]) -> Result<Self::Input> {
+        let (rules, msgs) = match split_sections(raw_input)[..] {
+            [rules, msgs] => (rules, msgs.lines.to_vec()),
+            [rules] => (rules, Vec::new()),
+            _ => return Err(Error::parse("expected rules and messages")),
+        };
+        let ruleset = rules.parse_lines(parse_rule_line)?.into_iter().collect();
+
+        Ok(MsgDecoder::new(ruleset, msgs))
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Answer1> {
+        input.count_matching()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Answer2> {
+        let mut input = input.clone();
+        let mut curr_idx = 255u8;
+        input.ruleset.insert(
+            curr_idx,
+            RuleOp::Or(Rule::Pointers(vec![42]), Rule::Pointers(vec![42, 42])),
+        );
+        input.ruleset.insert(
+            curr_idx - 1,
+            RuleOp::Or(
+                Rule::Pointers(vec![42, 31]),
+                Rule::Pointers(vec![42, 42, 31, 31]),
+            ),
+        );
+        curr_idx -= 2;
+        for _ in 0..2 {
+            input.ruleset.insert(
+                curr_idx,
+                RuleOp::Or(
+                    Rule::Pointers(vec![42]),
+                    Rule::Pointers(vec![42, curr_idx + 2]),
+                ),
+            );
+            input.ruleset.insert(
+                curr_idx - 1,
+                RuleOp::Or(
+                    Rule::Pointers(vec![42, 31]),
+                    Rule::Pointers(vec![42, curr_idx + 1, 31]),
+                ),
+            );
+            curr_idx -= 2;
+        }
+        input.ruleset.insert(
+            8,
+            RuleOp::Or(
+                Rule::Pointers(vec![42]),
+                Rule::Pointers(vec![42, curr_idx + 2]),
+            ),
+        );
+        input.ruleset.insert(
+            11,
+            RuleOp::Or(
+                Rule::Pointers(vec![42, 31]),
+                Rule::Pointers(vec![42, curr_idx + 1, 31]),
+            ),
+        );
+
+        input.count_matching()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::example_lines;
+
+    static EXAMPLE: &str = r#"0: 4 1 5
+1: 2 3 | 3 2
+2: 4 4 | 5 5
+3: 4 5 | 5 4
+4: "a"
+5: "b"
+
+ababbb
+bababa
+abbbab
+aaabbb
+aaaabbb"#;
+
+    static LOOPING: &str = r#"42: 9 14 | 10 1
+9: 14 27 | 1 26
+10: 23 14 | 28 1
+1: "a"
+11: 42 31
+5: 1 14 | 15 1
+19: 14 1 | 14 14
+12: 24 14 | 19 1
+16: 15 1 | 14 14
+31: 14 17 | 1 13
+6: 14 14 | 1 14
+2: 1 24 | 14 4
+0: 8 11
+13: 14 3 | 1 12
+15: 1 | 14
+17: 14 2 | 1 7
+23: 25 1 | 22 14
+28: 16 1
+4: 1 1
+20: 14 14 | 1 15
+3: 5 14 | 16 1
+27: 1 6 | 14 18
+14: "b"
+21: 14 1 | 1 14
+25: 1 1 | 1 14
+22: 14 14
+8: 42
+26: 14 22 | 1 20
+18: 15 15
+7: 14 5 | 1 21
+24: 14 1
+
+abbbbbabbbaaaababbaabbbbabababbbabbbbbbabaaaa
+bbabbbbaabaabba
+babbbbaabbbbbabbbbbbaabaaabaaa
+aaabbbbbbaaaabaababaabababbabaaabbababababaaa
+bbbbbbbaaaabbbbaaabbabaaa
+bbbababbbbaaaaaaaabbababaaababaabab
+ababaaaaaabaaab
+ababaaaaabbbaba
+baabbaaaabbaaaababbaababb
+abbbbabbbbaaaababbbbbbaaaababb
+aaaaabbaabaaaaababaa
+aaaabbaaaabbaaa
+aaaabbaabbaaaaaaabbbabbbaaabbaabaaa
+babaaabbbaaabaababbaabababaaab
+aabbbbbaabbbaaaaaabbbbbababaaaaabbaaabba"#;
+
+    #[test]
+    fn part1_examples() {
+        let input = Day19.parse(&example_lines(EXAMPLE)).unwrap();
+        assert_eq!(Day19.part1(&input).unwrap(), 2);
+        let input = Day19.parse(&example_lines(LOOPING)).unwrap();
+        assert_eq!(Day19.part1(&input).unwrap(), 3);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day19.parse(&example_lines(LOOPING)).unwrap();
+        assert_eq!(Day19.part2(&input).unwrap(), 12);
+    }
+
+    #[test]
+    fn built_by_hand() {
+        let rules = hashmap!(
+            0 => RuleOp::Or(Rule::Pointers(vec![1, 1]), Rule::Value("b".to_string())),
+            1 => RuleOp::Concat(Rule::Value("a".to_string()))
+        );
+        let msgs = vec!["aa".to_string(), "b".to_string(), "ab".to_string()];
+        let input = MsgDecoder::new(rules, msgs);
+        assert_eq!(Day19.part1(&input).unwrap(), 2);
+    }
+
+    #[test]
+    fn cyclic_rules() {
+        let input = Day19.parse(&example_lines("0: 1 0\n1: \"a\"\n\na")).unwrap();
+        let err = Day19.part1(&input).unwrap_err();
+        assert!(err.to_string().contains("cyclic rule: 0 -> 0"));
+        let input = Day19.parse(&example_lines("0: 1\n1: 2 | 3\n2: 1\n3: \"b\"")).unwrap();
+        assert!(Day19.part1(&input).is_err());
+    }
+}
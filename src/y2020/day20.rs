@@ -0,0 +1,584 @@
+use crate::common::{extract_one, split_by_header, Section};
+use crate::error::{Error, Result};
+use crate::grid::Grid;
+use crate::image::{Frame, Recorder, Rgb};
+use crate::solver::Solver;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
+
+lazy_static! {
+    static ref TITLE_REGX: Regex = Regex::new(r"^Tile (\d+):$").unwrap();
+    static ref SNAKE_ALIGNMENT: [Vec<usize>; 3] = [
+        vec![1, 4, 7, 10, 13, 16],
+        vec![0, 5, 6, 11, 12, 17, 18, 19],
+        vec![18]
+    ];
+}
+
+static SNAKE_HEIGHT: usize = 3;
+static SNAKE_LENGTH: usize = 20;
+
+#[derive(Debug, Clone)]
+pub struct Tile {
+    id: u32,
+    data: Grid<bool>,
+    borders: Vec<u16>,
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}", self.data.render(|&x| if x { '#' } else { '.' }))
+    }
+}
+
+impl Tile {
+    // Tiles are 10x10 cells, `true` for `#`
+    pub fn new(id: u32, data: Grid<bool>) -> Result<Tile> {
+        if data.width() != 10 || data.height() != 10 {
+            return Err(Error::parse("tile must be 10x10 cells"));
+        }
+
+        let unparsed_borders: Vec<Vec<bool>> = vec![
+            data.row(0).to_vec(),
+            data.column(9).copied().collect(),
+            data.row(9).iter().rev().copied().collect(),
+            data.column(0).rev().copied().collect(),
+        ];
+
+        let borders = unparsed_borders
+            .iter()
+            .map(|x| {
+                let mut res = 0u16;
+                for i in x.iter() {
+                    res <<= 1;
+                    if *i {
+                        res |= 1;
+                    }
+                }
+                res
+            })
+            .collect();
+
+        Ok(Tile { id, data, borders })
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn data(&self) -> &Grid<bool> {
+        &self.data
+    }
+
+    fn get_borders(&self) -> impl Iterator<Item = &u16> {
+        self.borders.iter()
+    }
+
+    fn get_flipped_borders(&self) -> Vec<u16> {
+        self.borders.iter().map(|b| Tile::flip_border(*b)).collect()
+    }
+
+    fn rotr_data(&mut self) {
+        self.data = self.data.rotate_right();
+    }
+
+    fn rotr_borders(&mut self) {
+        self.borders.rotate_right(1);
+    }
+
+    fn rotr(&mut self) {
+        self.rotr_borders();
+        self.rotr_data();
+    }
+
+    fn flip_borders(&mut self) {
+        let new_borders = self.borders.drain(..).map(Tile::flip_border).collect();
+        self.borders = new_borders;
+        let top = self.top();
+        let bottom = self.bottom();
+        self.borders[0] = bottom;
+        self.borders[2] = top;
+    }
+
+    fn flip_data(&mut self) {
+        self.data = self.data.flip_vertical();
+    }
+
+    fn flip(&mut self) {
+        self.flip_data();
+        self.flip_borders();
+    }
+
+    fn top(&self) -> u16 {
+        self.borders[0]
+    }
+
+    fn left(&self) -> u16 {
+        self.borders[3]
+    }
+
+    fn right(&self) -> u16 {
+        self.borders[1]
+    }
+
+    fn bottom(&self) -> u16 {
+        self.borders[2]
+    }
+
+    fn check_snake(&self, x: usize, y: usize) -> bool {
+        SNAKE_ALIGNMENT
+            .iter()
+            .enumerate()
+            .all(|(yy, xxs)| xxs.iter().all(|xx| self.data[(x + xx, y - yy)]))
+    }
+
+    fn kill_snake(&mut self, x: usize, y: usize) {
+        SNAKE_ALIGNMENT
+            .iter()
+            .enumerate()
+            .for_each(|(yy, xxs)| xxs.iter().for_each(|xx| self.data[(x + xx, y - yy)] = false));
+    }
+
+    // Returns where the snakes found start, their bottom left corner
+    fn find_and_destroy_snakes(&mut self) -> Vec<(usize, usize)> {
+        let y_max = self.data.height();
+        let x_max = self.data.width();
+
+        let coords: Vec<(usize, usize)> = (SNAKE_HEIGHT - 1..y_max)
+            .flat_map(|y| {
+                (0..x_max.saturating_sub(SNAKE_LENGTH - 1))
+                    .filter(|x| self.check_snake(*x, y))
+                    .map(|x| (x, y))
+                    .collect::<Vec<(usize, usize)>>()
+            })
+            .collect();
+
+        coords.iter().for_each(|&(x, y)| self.kill_snake(x, y));
+        coords
+    }
+
+    // Turns the image until it has snakes and returns their cells
+    fn obliterate_all_snakes(&mut self) -> Vec<(usize, usize)> {
+        let mut rot_counter = 0;
+        loop {
+            let snakes = self.find_and_destroy_snakes();
+            if !snakes.is_empty() {
+                return snakes
+                    .into_iter()
+                    .flat_map(|(x, y)| {
+                        SNAKE_ALIGNMENT.iter().enumerate().flat_map(move |(yy, xxs)| {
+                            xxs.iter().map(move |xx| (x + xx, y - yy))
+                        })
+                    })
+                    .collect();
+            }
+            self.rotr_data();
+            rot_counter += 1;
+
+            if rot_counter == 4 {
+                self.flip_data();
+            } else if rot_counter > 8 {
+                return Vec::new();
+            }
+        }
+    }
+
+    fn flip_border(val: u16) -> u16 {
+        val.reverse_bits() >> 6
+    }
+}
+
+fn count_borders(input: &InputType) -> HashMap<u16, HashSet<u32>> {
+    let mut border_counter: HashMap<u16, HashSet<u32>> = HashMap::new();
+    input.iter().for_each(|t| {
+        t.get_borders()
+            .chain(t.get_flipped_borders().iter())
+            .for_each(|x| {
+                border_counter.entry(*x).or_default().insert(t.id);
+            })
+    });
+    border_counter
+}
+
+fn map_neighbours(border_counter: &HashMap<u16, HashSet<u32>>) -> HashMap<u32, HashSet<u32>> {
+    let mut neigh_counter: HashMap<u32, HashSet<u32>> = HashMap::new();
+    border_counter.iter().for_each(|(_, tiles)| {
+        tiles.iter().for_each(|&id| {
+            tiles.iter().filter(|&&i| i != id).for_each(|&i| {
+                neigh_counter.entry(id).or_default().insert(i);
+            })
+        })
+    });
+    neigh_counter
+}
+
+fn _print_image(image: &Vec<Vec<Tile>>) {
+    for row in image {
+        for y in 0..row[0].data.height() {
+            for t in row {
+                for val in t.data.row(y) {
+                    print!("{} ", if *val { '#' } else { '.' });
+                }
+                print!("  ");
+            }
+            println!();
+        }
+        println!();
+    }
+}
+
+fn matched_tiles(wall: u16, wall_to_ids: &HashMap<u16, HashSet<u32>>) -> usize {
+    wall_to_ids.get(&wall).map_or(0, HashSet::len)
+}
+
+// Turns the corner so that its neighbours are to the right and below
+fn align_first_tile(tile: &mut Tile, wall_to_ids: &HashMap<u16, HashSet<u32>>) -> Result<()> {
+    for _ in 0..4 {
+        let is_right_match = matched_tiles(tile.right(), wall_to_ids) == 2;
+        let is_bottom_match = matched_tiles(tile.bottom(), wall_to_ids) == 2;
+
+        if is_right_match && is_bottom_match {
+            return Ok(());
+        }
+        tile.rotr();
+    }
+    Err(Error::solve(format!(
+        "corner tile {} has no adjacent matching borders",
+        tile.id
+    )))
+}
+
+fn match_tile<P>(
+    id: u32,
+    wall: u16,
+    wall_to_ids: &HashMap<u16, HashSet<u32>>,
+    tiles_left: &mut Vec<Tile>,
+    mut map_to_wall: P,
+) -> Result<Tile>
+where
+    P: FnMut(&Tile) -> u16,
+{
+    let unmatched = || Error::solve(format!("no tile left to put next to tile {}", id));
+    let tile_id = wall_to_ids
+        .get(&wall)
+        .and_then(|ids| ids.iter().find(|&x| x != &id))
+        .ok_or_else(unmatched)?;
+    let idx = tiles_left
+        .iter()
+        .position(|x| &x.id == tile_id)
+        .ok_or_else(unmatched)?;
+    let mut next = tiles_left.remove(idx);
+    if next.get_borders().all(|&x| x != wall) {
+        next.flip();
+    }
+    for _ in 0..4 {
+        if map_to_wall(&next) == wall {
+            return Ok(next);
+        }
+        next.rotr();
+    }
+    Err(unmatched())
+}
+
+fn merge_tiles(tiles: Vec<Vec<Tile>>) -> Result<Tile> {
+    let mut data: Vec<Vec<bool>> = Vec::new();
+    for row in tiles {
+        for y in 1..row[0].data.height() - 1 {
+            let mut new_row = Vec::new();
+            for t in &row {
+                let trimmed_row = t.data.row(y);
+                new_row.extend_from_slice(&trimmed_row[1..trimmed_row.len() - 1]);
+            }
+            data.push(new_row);
+        }
+    }
+    let data = Grid::from_rows(data)
+        .map_err(|_| Error::solve("rows of the image have different numbers of tiles"))?;
+    Ok(Tile {
+        id: 0,
+        data,
+        borders: Vec::new(),
+    })
+}
+
+fn parse_tile(section: &Section) -> Result<Tile> {
+    let (title, rows) = section
+        .split_header()
+        .ok_or_else(|| Error::parse("empty tile"))?;
+    let id = extract_one(&TITLE_REGX, title).map_err(|e| e.at_line(section.first_line, title))?;
+    let data = Grid::parse_from(rows.lines, rows.first_line, |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
+    .map_err(|e| match e.line() {
+        Some(_) => e,
+        None => e.at_line(section.first_line, title),
+    })?;
+    Tile::new(id, data).map_err(|e| e.at_line(section.first_line, title))
+}
+
+fn assemble(input: &InputType) -> Result<Tile> {
+    let wall_to_ids = count_borders(input);
+    let tile_to_ids = map_neighbours(&wall_to_ids);
+    let mut corner = input
+        .iter()
+        .find(|x| tile_to_ids.get(&x.id).is_some_and(|n| n.len() == 2))
+        .ok_or_else(|| Error::solve("no corner tile"))?
+        .clone();
+
+    let mut tiles_left: Vec<Tile> = input
+        .iter()
+        .filter(|&x| x.id != corner.id)
+        .cloned()
+        .collect();
+    align_first_tile(&mut corner, &wall_to_ids)?;
+
+    let mut image: Vec<Vec<Tile>> = Vec::new();
+    let mut row_start = corner;
+    loop {
+        let mut curr_id = row_start.id;
+        let mut curr_wall = Tile::flip_border(row_start.right());
+        let mut row = vec![row_start];
+        while matched_tiles(curr_wall, &wall_to_ids) > 1 {
+            let next = match_tile(curr_id, curr_wall, &wall_to_ids, &mut tiles_left, |x| {
+                x.left()
+            })?;
+            curr_id = next.id;
+            curr_wall = Tile::flip_border(next.right());
+            row.push(next);
+        }
+
+        let first_id = row[0].id;
+        let below = Tile::flip_border(row[0].bottom());
+        image.push(row);
+        if tiles_left.is_empty() {
+            break;
+        }
+
+        row_start = match_tile(first_id, below, &wall_to_ids, &mut tiles_left, |x| {
+            x.top()
+        })?;
+    }
+
+    merge_tiles(image)
+}
+
+const SEA: Rgb = [20, 50, 120];
+const ROUGH: Rgb = [120, 170, 220];
+const MONSTER: Rgb = [240, 140, 30];
+
+// The assembled image, turned the way the sea monsters can be found
+pub fn render(input: &InputType, recorder: &mut Recorder) -> Result<()> {
+    let mut image = assemble(input)?;
+    let monsters: HashSet<(usize, usize)> = image.obliterate_all_snakes().into_iter().collect();
+    let frame = Grid::from_fn(image.data.width(), image.data.height(), |pos| {
+        if monsters.contains(&pos) {
+            MONSTER
+        } else if image.data[pos] {
+            ROUGH
+        } else {
+            SEA
+        }
+    });
+    recorder.frame(Frame::Pixmap(frame))
+}
+
+pub type InputType = Vec<Tile>;
+
+pub struct Day20;
+
+impl Solver for Day20 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 20;
+    type Input = InputType;
+    type Answer1 = u64;
+    type Answer2 = usize;
+
+    fn parse(&self, raw_input: &[String]) -> Result<Self::Input> {
+        let tiles = split_by_header(raw_input, &TITLE_REGX)
+            .iter()
+            .map(parse_tile)
+            .collect::<Result<Vec<Tile>>>()?;
+        if tiles.is_empty() {
+            return Err(Error::parse("no tiles"));
+        }
+        Ok(tiles)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Answer1> {
+        let wall_to_ids = count_borders(input);
+        let corners: Vec<u64> = map_neighbours(&wall_to_ids)
+            .iter()
+            .filter(|(_, neighs)| neighs.len() == 2)
+            .map(|(&id, _)| id as u64)
+            .collect();
+        if corners.len() != 4 {
+            return Err(Error::solve(format!(
+                "found {} corner tiles",
+                corners.len()
+            )));
+        }
+        Ok(corners.iter().product::<u64>())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Answer2> {
+        let mut merged_image = assemble(input)?;
+        merged_image.obliterate_all_snakes();
+        Ok(merged_image.data.iter().filter(|&&x| x).count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::example_lines;
+
+    static EXAMPLE: &str = "\
+Tile 2311:
+..##.#..#.
+##..#.....
+#...##..#.
+####.#...#
+##.##.###.
+##...#.###
+.#.#.#..##
+..#....#..
+###...#.#.
+..###..###
+
+Tile 1951:
+#.##...##.
+#.####...#
+.....#..##
+#...######
+.##.#....#
+.###.#####
+###.##.##.
+.###....#.
+..#.#..#.#
+#...##.#..
+
+Tile 1171:
+####...##.
+#..##.#..#
+##.#..#.#.
+.###.####.
+..###.####
+.##....##.
+.#...####.
+#.##.####.
+####..#...
+.....##...
+
+Tile 1427:
+###.##.#..
+.#..#.##..
+.#.##.#..#
+#.#.#.##.#
+....#...##
+...##..##.
+...#.#####
+.#.####.#.
+..#..###.#
+..##.#..#.
+
+Tile 1489:
+##.#.#....
+..##...#..
+.##..##...
+..#...#...
+#####...#.
+#..#.#.#.#
+...#.#.#..
+##.#...##.
+..##.##.##
+###.##.#..
+
+Tile 2473:
+#....####.
+#..#.##...
+#.##..#...
+######.#.#
+.#...#.#.#
+.#########
+.###.#..#.
+########.#
+##...##.#.
+..###.#.#.
+
+Tile 2971:
+..#.#....#
+#...###...
+#.#.###...
+##.##..#..
+.#####..##
+.#..####.#
+#..#.#..#.
+..####.###
+..#.#.###.
+...#.#.#.#
+
+Tile 2729:
+...#.#.#.#
+####.#....
+..#.#.....
+....#..#.#
+.##..##.#.
+.#.####...
+####.#.#..
+##.####...
+##..#.##..
+#.##...##.
+
+Tile 3079:
+#.#.#####.
+.#..######
+..#.......
+######....
+####.#..#.
+.#...#.##.
+#.#####.##
+..#.###...
+..#.......
+..#.###...";
+
+    #[test]
+    fn part1_example() {
+        let input = Day20.parse(&example_lines(EXAMPLE)).unwrap();
+        assert_eq!(Day20.part1(&input).unwrap(), 20899048083289);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day20.parse(&example_lines(EXAMPLE)).unwrap();
+        assert_eq!(Day20.part2(&input).unwrap(), 273);
+    }
+
+    #[test]
+    fn tile_size() {
+        assert!(Tile::new(1, Grid::new(9, 10, false)).is_err());
+        let tile = Tile::new(7, Grid::new(10, 10, true)).unwrap();
+        assert_eq!(tile.id(), 7);
+        assert_eq!(tile.data().iter().filter(|&&x| x).count(), 100);
+    }
+
+    #[test]
+    fn unmatched_tiles() {
+        let mut tiles = example_lines(EXAMPLE);
+        tiles.truncate(11);
+        let input = Day20.parse(&tiles).unwrap();
+        assert!(Day20.part1(&input).is_err());
+        assert!(Day20.part2(&input).is_err());
+
+        // Eight tiles leave a hole in the 3x3 image
+        let mut tiles = example_lines(EXAMPLE);
+        tiles.truncate(tiles.len() - 12);
+        let input = Day20.parse(&tiles).unwrap();
+        assert!(Day20.part2(&input).is_err());
+    }
+}
@@ -0,0 +1,191 @@
+use crate::common::{extract, parse_lines};
+use crate::error::{Error, Result};
+use crate::solver::Solver;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+
+lazy_static! {
+    static ref PRODUCT_REGX: Regex = Regex::new(r"^([\w ]+) \(contains ([\w, ]+)\)$").unwrap();
+}
+type AllergenToProdMap = HashMap<String, HashSet<String>>;
+
+// None if some allergens can't be pinned down to a single product
+fn resolve_mapping(allergen_to_prods: &mut AllergenToProdMap) -> Option<HashMap<String, String>> {
+    let mut allerg_mapping: HashMap<String, String> = HashMap::new();
+
+    while !allergen_to_prods.is_empty() {
+        let singles: Vec<(String, String)> = allergen_to_prods
+            .iter()
+            .filter_map(|(al, prods)| {
+                if prods.len() == 1 {
+                    prods.iter().next().map(|p| (al.to_string(), p.to_string()))
+                } else {
+                    None
+                }
+            })
+            .collect();
+        if singles.is_empty() {
+            return None;
+        }
+
+        singles.iter().for_each(|(al, prod)| {
+            allerg_mapping.insert(al.to_string(), prod.to_string());
+
+            allergen_to_prods.remove(al);
+            allergen_to_prods.iter_mut().for_each(|(_, prods)| {
+                prods.remove(prod);
+            });
+        });
+    }
+
+    Some(allerg_mapping)
+}
+
+fn find_common_prods(input: &InputType) -> AllergenToProdMap {
+    input
+        .all_allergens
+        .iter()
+        .map(|al| {
+            let foods_with_al = input
+                .data
+                .iter()
+                .filter(|&f| f.allergens.contains(al))
+                .map(|f| &f.products)
+                .collect::<Vec<&HashSet<String>>>();
+
+            let common_prods: HashSet<String> = foods_with_al[0]
+                .iter()
+                .filter(|&prod| foods_with_al[1..].iter().all(|food| food.contains(prod)))
+                .map(|prod| prod.to_string())
+                .collect();
+            (al.to_string(), common_prods)
+        })
+        .collect()
+}
+
+pub struct Food {
+    products: HashSet<String>,
+    allergens: HashSet<String>,
+}
+
+impl Food {
+    pub fn new(products: HashSet<String>, allergens: HashSet<String>) -> Self {
+        Food {
+            products,
+            allergens,
+        }
+    }
+
+    pub fn products(&self) -> &HashSet<String> {
+        &self.products
+    }
+
+    pub fn allergens(&self) -> &HashSet<String> {
+        &self.allergens
+    }
+}
+
+pub struct FoodList {
+    data: Vec<Food>,
+    all_allergens: HashSet<String>,
+}
+
+impl FoodList {
+    pub fn new(data: Vec<Food>) -> Self {
+        let all_allergens = data.iter().flat_map(|f| f.allergens.iter().cloned()).collect();
+        FoodList {
+            data,
+            all_allergens,
+        }
+    }
+
+    pub fn foods(&self) -> &[Food] {
+        &self.data
+    }
+
+    // Every allergen listed on some food
+    pub fn allergens(&self) -> &HashSet<String> {
+        &self.all_allergens
+    }
+}
+
+pub type InputType = FoodList;
+
+fn identify_allergens(input: &InputType) -> Result<HashMap<String, String>> {
+    let mut allergen_to_prods = find_common_prods(input);
+    resolve_mapping(&mut allergen_to_prods)
+        .ok_or_else(|| Error::solve("allergens cannot be matched to single ingredients"))
+}
+
+pub struct Day21;
+
+impl Solver for Day21 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 21;
+    type Input = InputType;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(&self, raw_input: &[String]) -> Result<Self::Input> {
+        let data = parse_lines(raw_input, |x| {
+            let (prod_str, allerg_str): (String, String) = extract(&PRODUCT_REGX, x)?;
+            let products: HashSet<String> =
+                prod_str.split_whitespace().map(|x| x.to_string()).collect();
+            let allergens: HashSet<String> =
+                allerg_str.split(", ").map(|x| x.to_string()).collect();
+
+            Ok(Food::new(products, allergens))
+        })?;
+        Ok(FoodList::new(data))
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Answer1> {
+        let identified = identify_allergens(input)?;
+        let allergic_prods: HashSet<String> = identified.values().map(|x| x.to_string()).collect();
+
+        Ok(input
+            .data
+            .iter()
+            .flat_map(|f| f.products.iter())
+            .filter(|&prod| !allergic_prods.contains(prod))
+            .count())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Answer2> {
+        let identified = identify_allergens(input)?;
+        let mut allergens_sorted: Vec<String> =
+            input.all_allergens.iter().map(|x| x.to_string()).collect();
+        allergens_sorted.sort_unstable();
+
+        Ok(allergens_sorted
+            .iter()
+            .filter_map(|key| identified.get(key).map(|x| x.to_string()))
+            .collect::<Vec<String>>()
+            .join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::example_lines;
+
+    static EXAMPLE: &str = "\
+mxmxvkd kfcds sqjhc nhms (contains dairy, fish)
+trh fvjkl sbzzf mxmxvkd (contains dairy)
+sqjhc fvjkl (contains soy)
+sqjhc mxmxvkd sbzzf (contains fish)";
+
+    #[test]
+    fn part1_example() {
+        let input = Day21.parse(&example_lines(EXAMPLE)).unwrap();
+        assert_eq!(Day21.part1(&input).unwrap(), 5);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day21.parse(&example_lines(EXAMPLE)).unwrap();
+        assert_eq!(Day21.part2(&input).unwrap(), "mxmxvkd,sqjhc,fvjkl");
+    }
+}
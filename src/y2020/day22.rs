@@ -0,0 +1,214 @@
+use crate::common::{parse_value, split_sections, Section};
+use crate::error::{Error, Result};
+use crate::solver::Solver;
+use std::{
+    collections::{hash_map::DefaultHasher, HashSet, VecDeque},
+    hash::{Hash, Hasher},
+};
+
+#[derive(Clone)]
+pub struct Combat {
+    player1: VecDeque<u8>,
+    player2: VecDeque<u8>,
+    seen_states: HashSet<u64>,
+}
+
+impl Combat {
+    // Decks are listed from the top card down
+    pub fn new(p1: VecDeque<u8>, p2: VecDeque<u8>) -> Combat {
+        Combat {
+            player1: p1,
+            player2: p2,
+            seen_states: HashSet::new(),
+        }
+    }
+
+    pub fn player1(&self) -> &VecDeque<u8> {
+        &self.player1
+    }
+
+    pub fn player2(&self) -> &VecDeque<u8> {
+        &self.player2
+    }
+
+    fn is_finished(&self) -> bool {
+        self.player1.is_empty() || self.player2.is_empty()
+    }
+
+    fn get_winner(&self) -> &VecDeque<u8> {
+        if self.player1.is_empty() {
+            &self.player2
+        } else {
+            &self.player1
+        }
+    }
+
+    fn save_state(&mut self) -> bool {
+        let mut hasher = DefaultHasher::new();
+        self.player1.hash(&mut hasher);
+        self.seen_states.insert(hasher.finish())
+    }
+
+    fn sub_game_needed(&self, p1: u8, p2: u8) -> bool {
+        p1 as usize <= self.player1.len() && p2 as usize <= self.player2.len()
+    }
+
+    // Top cards of both players, none once either deck is empty
+    fn draw(&mut self) -> Option<(u8, u8)> {
+        if self.is_finished() {
+            return None;
+        }
+        Some((self.player1.pop_front()?, self.player2.pop_front()?))
+    }
+
+    fn play(&mut self) {
+        while let Some((p1, p2)) = self.draw() {
+            if p1 > p2 {
+                self.player1.push_back(p1);
+                self.player1.push_back(p2);
+            } else {
+                self.player2.push_back(p2);
+                self.player2.push_back(p1);
+            }
+        }
+    }
+
+    fn play_recursive(&mut self) -> bool {
+        while !self.is_finished() && self.save_state() {
+            let (p1, p2) = match self.draw() {
+                Some(cards) => cards,
+                None => break,
+            };
+
+            let has_p1_won = {
+                if self.sub_game_needed(p1, p2) {
+                    let mut sub_game = Combat::new(
+                        self.player1.iter().copied().take(p1 as usize).collect(),
+                        self.player2.iter().copied().take(p2 as usize).collect(),
+                    );
+                    sub_game.play_recursive()
+                } else {
+                    p1 > p2
+                }
+            };
+
+            if has_p1_won {
+                self.player1.push_back(p1);
+                self.player1.push_back(p2);
+            } else {
+                self.player2.push_back(p2);
+                self.player2.push_back(p1);
+            }
+        }
+
+        !(self.is_finished() && self.player1.is_empty())
+    }
+}
+
+fn parse_deck(section: &Section, header: &str) -> Result<VecDeque<u8>> {
+    match section.split_header() {
+        Some((title, cards)) if title == header => Ok(cards.parse_lines(parse_value)?.into()),
+        _ => Err(Error::parse(format!("expected `{}`", header))
+            .at_line(section.first_line, &section.lines[0])),
+    }
+}
+
+pub struct Day22;
+
+impl Solver for Day22 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 22;
+    type Input = Combat;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(&self, raw_input: &[String]) -> Result<Self::Input> {
+        let (player1, player2) = match split_sections(raw_input)[..] {
+            [deck1, deck2] => (
+                parse_deck(&deck1, "Player 1:")?,
+                parse_deck(&deck2, "Player 2:")?,
+            ),
+            _ => return Err(Error::parse("expected the decks of two players")),
+        };
+
+        Ok(Combat::new(player1, player2))
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Answer1> {
+        let mut game = input.clone();
+        game.play();
+
+        Ok(game
+            .get_winner()
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(i, x)| (i as u64 + 1, *x as u64))
+            .fold(0u64, |acc, (i, x)| acc + (i * x)))
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Answer2> {
+        let mut game = input.clone();
+        let res = game.play_recursive();
+
+        let winner = if res { &game.player1 } else { &game.player2 };
+
+        Ok(winner
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(i, x)| (i as u64 + 1, *x as u64))
+            .fold(0u64, |acc, (i, x)| acc + (i * x)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::example_lines;
+
+    static EXAMPLE: &str = "\
+Player 1:
+9
+2
+6
+3
+1
+
+Player 2:
+5
+8
+4
+7
+10";
+
+    #[test]
+    fn part1_example() {
+        let input = Day22.parse(&example_lines(EXAMPLE)).unwrap();
+        assert_eq!(Day22.part1(&input).unwrap(), 306);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day22.parse(&example_lines(EXAMPLE)).unwrap();
+        assert_eq!(Day22.part2(&input).unwrap(), 291);
+    }
+
+    // Without the repeated state rule this game would never end
+    #[test]
+    fn part2_terminates() {
+        let input = Day22
+            .parse(&example_lines("Player 1:\n43\n19\n\nPlayer 2:\n2\n29\n14"))
+            .unwrap();
+        assert!(Day22.part2(&input).is_ok());
+    }
+
+    #[test]
+    fn empty_deck() {
+        let input = Day22
+            .parse(&example_lines("Player 1:\n\nPlayer 2:\n3\n1"))
+            .unwrap();
+        assert_eq!(Day22.part1(&input).unwrap(), 7);
+        assert_eq!(Day22.part2(&input).unwrap(), 7);
+    }
+}
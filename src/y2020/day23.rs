@@ -0,0 +1,142 @@
+use crate::error::{Error, Result};
+use crate::solver::Solver;
+use itertools::iterate;
+
+fn create_next_list_small(input: &InputType) -> [usize; 10] {
+    let mut next: [usize; 10] = [0; 10];
+    input
+        .windows(2)
+        .for_each(|w| next[w[0] as usize] = w[1] as usize);
+    next[0] = *input.first().unwrap() as usize;
+    next[*input.last().unwrap() as usize] = next[0];
+    next
+}
+
+fn fill_next_list_big(input: &InputType, next: &mut [usize]) {
+    input
+        .windows(2)
+        .for_each(|w| next[w[0] as usize] = w[1] as usize);
+    next[0] = *input.first().unwrap() as usize;
+
+    let mut pp = *input.last().unwrap() as usize;
+    let mut nn = input.len() + 1;
+    while pp < next.len() - 1 {
+        next[pp] = nn;
+        pp = nn;
+        nn += 1;
+    }
+    next[pp] = next[0];
+}
+
+fn do_da_crab(next: &mut [usize], moves_no: usize) {
+    let mut c = 0;
+    for _ in 0..moves_no {
+        c = next[c];
+        let p1 = next[c];
+        let p2 = next[p1];
+        let p3 = next[p2];
+        let mut dst = c - 1;
+        while dst == p1 || dst == p2 || dst == p3 || dst == 0 {
+            if dst == 0 {
+                dst = next.len() - 1;
+            } else {
+                dst -= 1;
+            }
+        }
+        next[c] = next[p3];
+        next[p3] = next[dst];
+        next[dst] = p1;
+    }
+}
+
+pub type InputType = Vec<u32>;
+
+const CUPS: u32 = 9;
+
+pub struct Day23;
+
+impl Solver for Day23 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 23;
+    type Input = InputType;
+    type Answer1 = String;
+    type Answer2 = usize;
+
+    fn parse(&self, raw_input: &[String]) -> Result<Self::Input> {
+        let line = raw_input
+            .first()
+            .ok_or_else(|| Error::parse("missing cup labels"))?;
+        let cups = line
+            .chars()
+            .map(|x| {
+                x.to_digit(10)
+                    .ok_or_else(|| Error::parse(format!("`{}` is not a cup label", x)))
+            })
+            .collect::<Result<Vec<u32>>>()
+            .map_err(|e| e.at_line(1, line))?;
+
+        // Cups are used as indices into lists sized for 9 cups
+        let mut sorted = cups.clone();
+        sorted.sort_unstable();
+        if !sorted.iter().copied().eq(1..=CUPS) {
+            return Err(
+                Error::parse("cups must be labeled with the distinct digits 1 to 9")
+                    .at_line(1, line),
+            );
+        }
+        Ok(cups)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Answer1> {
+        let mut next = create_next_list_small(input);
+        do_da_crab(&mut next, 100);
+
+        Ok(iterate(next[1], |&c| next[c])
+            .take_while(|&c| c != 1)
+            .flat_map(|x| x.to_string().chars().collect::<Vec<char>>())
+            .collect::<String>())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Answer2> {
+        let mut next = vec![0usize; 1_000_001];
+        fill_next_list_big(input, &mut next);
+        do_da_crab(&mut next, 10_000_000);
+        Ok(next[1] * next[next[1]])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::example_lines;
+
+    static EXAMPLE: &str = "389125467";
+
+    #[test]
+    fn part1_example() {
+        let input = Day23.parse(&example_lines(EXAMPLE)).unwrap();
+        assert_eq!(Day23.part1(&input).unwrap(), "67384529");
+
+        let mut next = create_next_list_small(&input);
+        do_da_crab(&mut next, 10);
+        let labels: String = iterate(next[1], |&c| next[c])
+            .take_while(|&c| c != 1)
+            .map(|x| x.to_string())
+            .collect();
+        assert_eq!(labels, "92658374");
+    }
+
+    #[test]
+    fn rejects_other_cup_counts() {
+        for bad in &["", "312", "21", "12345678", "1234567890", "123456788", "12345678a"] {
+            assert!(Day23.parse(&example_lines(bad)).is_err(), "{:?}", bad);
+        }
+        assert!(Day23.parse(&[]).is_err());
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day23.parse(&example_lines(EXAMPLE)).unwrap();
+        assert_eq!(Day23.part2(&input).unwrap(), 149245887792);
+    }
+}
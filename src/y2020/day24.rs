@@ -0,0 +1,144 @@
+use crate::automaton::{Automaton, HexGrid, Rule, Sparse};
+use crate::common::{parse_lines, Part};
+use crate::error::{Error, Result};
+use crate::grid::Grid;
+use crate::hex::{self, Direction, Hex};
+use crate::image::{Frame, Recorder, Rgb};
+use crate::points::{Bounds, Point2};
+use crate::solver::Solver;
+use std::collections::HashSet;
+
+fn paint_floor(input: &InputType) -> HashSet<Hex> {
+    let mut black_tiles: HashSet<Hex> = HashSet::new();
+    for dirs in input {
+        let pos = Hex::origin().walk(dirs);
+
+        if black_tiles.contains(&pos) {
+            black_tiles.remove(&pos);
+        } else {
+            black_tiles.insert(pos);
+        }
+    }
+
+    black_tiles
+}
+
+const DAYS: usize = 100;
+const WHITE: Rgb = [230, 230, 220];
+const BLACK: Rgb = [30, 30, 30];
+
+// Black tiles are alive, they stay black with 1 or 2 black neighbours
+fn exhibit(input: &InputType) -> Result<Sparse<HexGrid>> {
+    Sparse::new(HexGrid, Rule::new(&[2], &[1, 2])?, paint_floor(input))
+}
+
+// Every hex is a 2 by 2 block in doubled coordinates, so every other row is
+// shifted by half a hex. The floor grows by at most one hex a day, so all
+// frames can have the same size.
+fn render_floor(mut floor: Sparse<HexGrid>, days: usize, recorder: &mut Recorder) -> Result<()> {
+    let corners: Vec<Point2> = floor
+        .alive()
+        .map(|x| {
+            let (col, row) = x.to_doubled();
+            Point2::new([col, row])
+        })
+        .collect();
+    let bounds = Bounds::of(&corners).ok_or_else(|| Error::solve("no black tiles"))?;
+    let margin = Point2::new([2 * days as i32, days as i32]);
+    let (min, size) = (bounds.min - margin, bounds.size() + margin * 2);
+    let (width, height) = (size[0] as usize + 1, 2 * size[1] as usize);
+
+    let frame = |floor: &Sparse<HexGrid>| {
+        Frame::Pixmap(Grid::from_fn(width, height, |(x, y)| {
+            let row = min[1] + (y / 2) as i32;
+            let col = min[0] + x as i32;
+            let hex = Hex::from_doubled(col, row).or_else(|| Hex::from_doubled(col - 1, row));
+            match hex {
+                Some(hex) if floor.is_alive(&hex) => BLACK,
+                _ => WHITE,
+            }
+        }))
+    };
+
+    recorder.frame(frame(&floor))?;
+    for _ in 0..days {
+        floor.step();
+        recorder.frame(frame(&floor))?;
+    }
+    Ok(())
+}
+
+// The tiles flipped by the instructions, and for part 2 the exhibit every day
+pub fn render(input: &InputType, part: Part, recorder: &mut Recorder) -> Result<()> {
+    let days = match part {
+        Part::One => 0,
+        Part::Two => DAYS,
+    };
+    render_floor(exhibit(input)?, days, recorder)
+}
+
+pub type InputType = Vec<Vec<Direction>>;
+
+pub struct Day24;
+
+impl Solver for Day24 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 24;
+    type Input = InputType;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, raw_input: &[String]) -> Result<Self::Input> {
+        parse_lines(raw_input, hex::parse_path)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Answer1> {
+        Ok(paint_floor(input).len())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Answer2> {
+        let mut floor = exhibit(input)?;
+        floor.run(DAYS);
+        Ok(floor.population())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::example_lines;
+
+    static EXAMPLE: &str = "\
+sesenwnenenewseeswwswswwnenewsewsw
+neeenesenwnwwswnenewnwwsewnenwseswesw
+seswneswswsenwwnwse
+nwnwneseeswswnenewneswwnewseswneseene
+swweswneswnenwsewnwneneseenw
+eesenwseswswnenwswnwnwsewwnwsene
+sewnenenenesenwsewnenwwwse
+wenwwweseeeweswwwnwwe
+wsweesenenewnwwnwsenewsenwwsesesenwne
+neeswseenwwswnwswswnw
+nenwswwsewswnenenewsenwsenwnesesenew
+enewnwewneswsewnwswenweswnenwsenwsw
+sweneswneswneneenwnewenewwneswswnese
+swwesenesewenwneswnwwneseswwne
+enesenwswwswneneswsenwnewswseenwsese
+wnwnesenesenenwwnenwsewesewsesesew
+nenewswnwewswnenesenwnesewesw
+eneswnwswnwsenenwnwnwwseeswneewsenese
+neswnwewnwnwseenwseesewsenwsweewe
+wseweeenwnesenwwwswnew";
+
+    #[test]
+    fn part1_example() {
+        let input = Day24.parse(&example_lines(EXAMPLE)).unwrap();
+        assert_eq!(Day24.part1(&input).unwrap(), 10);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day24.parse(&example_lines(EXAMPLE)).unwrap();
+        assert_eq!(Day24.part2(&input).unwrap(), 2208);
+    }
+}
@@ -0,0 +1,118 @@
+use crate::common::{parse_lines, parse_value};
+use crate::error::{Error, Result};
+use crate::solver::Solver;
+
+static MODULUS: u64 = 20201227;
+
+fn transform(subject: u64, loop_size: usize) -> u64 {
+    let mut val = 1;
+    for _ in 0..loop_size {
+        val = (val * subject) % MODULUS;
+    }
+    val
+}
+
+fn transform_until(subject: u64, result: u64) -> usize {
+    let mut val = 1;
+    let mut loop_size = 0;
+    while val != result {
+        val = (val * subject) % MODULUS;
+        loop_size += 1;
+    }
+    loop_size
+}
+
+// Anything outside of the group would make the loop size search spin forever
+fn check_key(key: u64) -> Result<u64> {
+    if key == 0 || key >= MODULUS {
+        return Err(Error::parse(format!(
+            "public key must be between 1 and {}",
+            MODULUS - 1
+        )));
+    }
+    Ok(key)
+}
+
+pub struct PubKeys {
+    card_pub_key: u64,
+    door_pub_key: u64,
+}
+
+impl PubKeys {
+    pub fn new(card_pub_key: u64, door_pub_key: u64) -> Result<Self> {
+        Ok(PubKeys {
+            card_pub_key: check_key(card_pub_key)?,
+            door_pub_key: check_key(door_pub_key)?,
+        })
+    }
+
+    pub fn card(&self) -> u64 {
+        self.card_pub_key
+    }
+
+    pub fn door(&self) -> u64 {
+        self.door_pub_key
+    }
+}
+
+pub struct Day25;
+
+impl Solver for Day25 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 25;
+    type Input = PubKeys;
+    type Answer1 = u64;
+    type Answer2 = ();
+
+    fn parse(&self, raw_input: &[String]) -> Result<Self::Input> {
+        let keys = parse_lines(raw_input, |x| check_key(parse_value(x)?))?;
+        match keys[..] {
+            [card_pub_key, door_pub_key] => PubKeys::new(card_pub_key, door_pub_key),
+            _ => Err(Error::parse("expected exactly two public keys")),
+        }
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Answer1> {
+        let card_ls = transform_until(7, input.card_pub_key);
+        let enc_key_card = transform(input.door_pub_key, card_ls);
+        // let door_ls = transform_until(7, input.door_pub_key);
+        // let enc_key_door = transform(input.card_pub_key, door_ls);
+        // assert_eq!(enc_key_card, enc_key_door);
+        Ok(enc_key_card)
+    }
+
+    // The last day only has one puzzle
+    fn part2(&self, _input: &Self::Input) -> Result<Self::Answer2> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::example_lines;
+
+    static EXAMPLE: &str = "\
+5764801
+17807724";
+
+    #[test]
+    fn loop_sizes() {
+        assert_eq!(transform_until(7, 5764801), 8);
+        assert_eq!(transform_until(7, 17807724), 11);
+    }
+
+    #[test]
+    fn part1_example() {
+        let input = Day25.parse(&example_lines(EXAMPLE)).unwrap();
+        assert_eq!(Day25.part1(&input).unwrap(), 14897079);
+    }
+
+    #[test]
+    fn key_range() {
+        assert!(PubKeys::new(5764801, 17807724).is_ok());
+        assert!(PubKeys::new(0, 17807724).is_err());
+        assert!(PubKeys::new(5764801, MODULUS).is_err());
+        assert!(Day25.parse(&example_lines("5764801\n20201227")).is_err());
+    }
+}